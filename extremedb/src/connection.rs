@@ -43,11 +43,11 @@
 //! # }
 //! ```
 
-use std::marker::PhantomData;
 use std::mem::MaybeUninit;
 
 use crate::database::Database;
-use crate::{exdb_sys, mco_ret, result_from_code, Result};
+use crate::runtime::{Runtime, TxManager};
+use crate::{exdb_sys, mco_ret, result_from_code, Error, Result};
 
 /// A database connection.
 ///
@@ -56,7 +56,7 @@ use crate::{exdb_sys, mco_ret, result_from_code, Result};
 ///
 /// A connection is closed when it is dropped.
 pub struct Connection<'a> {
-    db: PhantomData<&'a Database<'a>>,
+    db: &'a Database<'a>,
     pub(crate) h: exdb_sys::mco_db_h,
 }
 
@@ -67,20 +67,86 @@ impl<'a> Connection<'a> {
 
         result_from_code(unsafe { exdb_sys::mco_db_connect(db.name().as_ptr(), h.as_mut_ptr()) })?;
 
+        db.connection_opened();
+
         Ok(Connection {
-            db: PhantomData,
+            db,
             h: unsafe { h.assume_init() },
         })
     }
 
+    /// Checks that the application is linked with the `expected`
+    /// transaction manager.
+    ///
+    /// Returns an `MCO_E_UNSUPPORTED` error if the linked transaction
+    /// manager is different or unknown (see
+    /// [`Info::transaction_manager()`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use extremedb::{connection, database, runtime, Result};
+    /// # use extremedb::device::util;
+    /// # use extremedb::runtime::TxManager;
+    /// #
+    /// # fn main() -> Result<()> {
+    /// #     let runtime = runtime::Runtime::start(vec![]);
+    /// #
+    /// #     let mut devs = util::DeviceContainer::new();
+    /// #
+    /// #     let db = database::Database::open(
+    /// #         &runtime,
+    /// #         "test_db",
+    /// #         None,
+    /// #         devs.devices(),
+    /// #         database::Params::new(),
+    /// #     )?;
+    /// #
+    ///     let conn = connection::Connection::new(&db)?;
+    ///
+    ///     let actual = runtime.info().transaction_manager().unwrap();
+    ///     conn.require_tmgr(actual)?;
+    ///
+    ///     let other = if actual == TxManager::Mvcc {
+    ///         TxManager::Exclusive
+    ///     } else {
+    ///         TxManager::Mvcc
+    ///     };
+    ///
+    ///     assert!(conn.require_tmgr(other).is_err());
+    /// #
+    /// #     drop(conn);
+    /// #     drop(db);
+    /// #     drop(runtime);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Info::transaction_manager()`]: ../runtime/struct.Info.html#method.transaction_manager
+    pub fn require_tmgr(&self, expected: TxManager) -> Result<()> {
+        let rt_info = Runtime::info_impl();
+        if rt_info.transaction_manager() == Some(expected) {
+            Ok(())
+        } else {
+            Err(Error::new_core(mco_ret::MCO_E_UNSUPPORTED))
+        }
+    }
+
     pub(crate) unsafe fn handle(&self) -> exdb_sys::mco_db_h {
         self.h
     }
+
+    #[cfg(feature = "sql")]
+    pub(crate) fn database(&self) -> &'a Database<'a> {
+        self.db
+    }
 }
 
 impl<'a> Drop for Connection<'a> {
     fn drop(&mut self) {
         let rc = unsafe { exdb_sys::mco_db_disconnect(self.h) };
         debug_assert_eq!(mco_ret::MCO_S_OK, rc);
+        self.db.connection_closed();
     }
 }
@@ -107,14 +107,16 @@
 //! }
 //! ```
 
+use std::cell::Cell;
 use std::ffi::{c_void, CStr, CString};
 use std::marker::PhantomData;
 use std::mem::{self, MaybeUninit};
 use std::ptr;
+use std::sync::atomic::{AtomicU64, Ordering};
 
 use crate::device::Device;
 use crate::dict;
-use crate::runtime::Runtime;
+use crate::runtime::{Runtime, TxManager};
 use crate::util::BitMask32;
 use crate::{exdb_sys, mco_ret, result_from_code, Error, Result};
 
@@ -780,6 +782,9 @@ impl Params {
     db_param_scalar!(
         /// Sets the factor used to determine when a hash index table
         /// is extended.
+        ///
+        /// The value is a percentage of the hash table fill, and must be in
+        /// the range 1 to 100; see [`validate()`](#method.validate).
         hash_load_factor,
         /// Returns the current parameter value.
         get_hash_load_factor,
@@ -789,6 +794,9 @@ impl Params {
     db_param_scalar!(
         /// Sets the maximum number of active write transactions when
         /// optimistic locking of B-Tree indexes is being performed.
+        ///
+        /// Setting this parameter to zero effectively disables the
+        /// optimistic locking of the indexes.
         index_optimistic_lock_threshold,
         /// Returns the current parameter value.
         get_index_optimistic_lock_threshold,
@@ -961,6 +969,40 @@ impl Params {
         u64
     );
 
+    db_param_scalar!(
+        /// Sets the maximum number of stale object versions kept by the MVCC
+        /// transaction manager.
+        ///
+        /// When this limit is reached, the stale versions are garbage
+        /// collected. Higher values let the MVCC transaction manager retain
+        /// more versions, which can reduce the garbage collection overhead
+        /// at the cost of the database memory. This parameter has no effect
+        /// with the other transaction managers.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// # use extremedb::{database, runtime};
+        /// # use extremedb::device::util;
+        /// # fn main() -> extremedb::Result<()> {
+        /// #     let runtime = runtime::Runtime::start(vec![]);
+        ///     let mut db_params = database::Params::new();
+        ///     db_params.max_gc_versions(1000);
+        ///     assert_eq!(db_params.get_max_gc_versions(), 1000);
+        ///
+        /// #     let mut devs = util::DeviceContainer::new();
+        ///     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+        /// #     drop(db);
+        /// #     Ok(())
+        /// # }
+        /// ```
+        max_gc_versions,
+        /// Returns the current parameter value.
+        get_max_gc_versions,
+        u32,
+        i32
+    );
+
     db_param_scalar!(
         /// Sets the maximum number of pages in the page hash used internally
         /// by the *e*X*treme*DB runtime when encryption or data compression
@@ -1023,6 +1065,16 @@ impl Params {
     db_param_scalar!(
         /// Sets the size of a bitmap used internally to accelerate
         /// the performance of the MVCC transaction manager in some cases.
+        ///
+        /// The bitmap is indexed by the database pages. An undersized bitmap
+        /// does not cause errors, but degrades the performance under heavy
+        /// concurrent load. It is recommended to make the bitmap at least
+        /// as large as one bit per in-memory database page, i.e. the total
+        /// size of the devices assigned to the database divided by
+        /// `mem_page_size * 8`. Zero (the default) lets the runtime choose
+        /// the size. See [`check_mvcc_bitmap_size()`].
+        ///
+        /// [`check_mvcc_bitmap_size()`]: #method.check_mvcc_bitmap_size
         mvcc_bitmap_size,
         /// Returns the current parameter value.
         get_mvcc_bitmap_size,
@@ -1127,6 +1179,156 @@ impl Params {
         get_file_backup_delay,
         u32
     );
+
+    /// Checks the parameters for invalid combinations which are not
+    /// detected by the *e*X*treme*DB runtime when the database is opened.
+    ///
+    /// `devs` are the devices that will be used to open the database. The
+    /// following checks are performed:
+    ///
+    /// - The copy-on-write page map size must be nonzero if the in-memory
+    ///   page compression is enabled, since the MVCC transaction manager
+    ///   relies on the page map for the compressed pages;
+    /// - The additional heap size must not exceed the total size of the
+    ///   in-memory devices assigned to the database;
+    /// - The hash load factor must be in the range 1 to 100 (percent).
+    ///
+    /// An undersized MVCC bitmap only degrades the performance, and is
+    /// reported separately by [`check_mvcc_bitmap_size()`].
+    ///
+    /// The [index optimistic lock threshold] is not checked: zero is the
+    /// supported way to disable the optimistic locking of the indexes, and
+    /// the parameters have no other setting indicating that the optimistic
+    /// locking is intended.
+    ///
+    /// Returns `MCO_E_ILLEGAL_PARAM` if any of the checks fails.
+    ///
+    /// [`check_mvcc_bitmap_size()`]: #method.check_mvcc_bitmap_size
+    /// [index optimistic lock threshold]: #method.index_optimistic_lock_threshold
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use extremedb::database::{ModeMask, Params};
+    /// # use extremedb::device::{Assignment, Device};
+    /// # use extremedb::runtime::Runtime;
+    /// # fn main() -> extremedb::Result<()> {
+    /// # let runtime = Runtime::start(vec![]);
+    /// let devs = vec![Device::new_mem_conv(Assignment::Database, 1024 * 1024)?];
+    ///
+    /// let mut params = Params::new();
+    /// params.additional_heap_size(64 * 1024);
+    /// assert!(params.validate(&devs).is_ok());
+    ///
+    /// // The heap is larger than the database memory.
+    /// params.additional_heap_size(2 * 1024 * 1024);
+    /// assert!(params.validate(&devs).is_err());
+    ///
+    /// // No page map for the compressed pages.
+    /// let mut params = Params::new();
+    /// let mut mode = ModeMask::new();
+    /// mode.inmemory_compression(true);
+    /// params.mode_mask(mode).cow_pagemap_size(0);
+    /// assert!(params.validate(&devs).is_err());
+    ///
+    /// // Hash load factor out of range.
+    /// let mut params = Params::new();
+    /// params.hash_load_factor(0);
+    /// assert!(params.validate(&devs).is_err());
+    /// params.hash_load_factor(101);
+    /// assert!(params.validate(&devs).is_err());
+    /// params.hash_load_factor(75);
+    /// assert!(params.validate(&devs).is_ok());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn validate(&self, devs: &[Device]) -> Result<()> {
+        if self.get_mode_mask().get_inmemory_compression() && self.p.cow_pagemap_size == 0 {
+            return Err(Error::new_core(mco_ret::MCO_E_ILLEGAL_PARAM));
+        }
+
+        let mem_size = Params::database_memory_size(devs);
+
+        // The setter accepts u32 values which may overflow the C int.
+        if self.p.additional_heap_size < 0
+            || self.p.additional_heap_size as exdb_sys::mco_size_t > mem_size
+        {
+            return Err(Error::new_core(mco_ret::MCO_E_ILLEGAL_PARAM));
+        }
+
+        if self.p.hash_load_factor == 0 || self.p.hash_load_factor > 100 {
+            return Err(Error::new_core(mco_ret::MCO_E_ILLEGAL_PARAM));
+        }
+
+        Ok(())
+    }
+
+    /// Checks whether the MVCC bitmap is large enough for the devices.
+    ///
+    /// This check is advisory: an undersized bitmap does not prevent the
+    /// database from being opened, but degrades the performance of the
+    /// MVCC transaction manager (see [`mvcc_bitmap_size()`]). The bitmap
+    /// should provide at least one bit per in-memory database page.
+    ///
+    /// Returns `MCO_E_ILLEGAL_PARAM` if the application uses the MVCC
+    /// transaction manager (see [`Info::transaction_manager()`]) and the
+    /// bitmap size is set to a nonzero value below the recommended one.
+    /// The default size of zero is always accepted.
+    ///
+    /// [`mvcc_bitmap_size()`]: #method.mvcc_bitmap_size
+    /// [`Info::transaction_manager()`]: ../runtime/struct.Info.html#method.transaction_manager
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use extremedb::database::Params;
+    /// # use extremedb::device::{Assignment, Device};
+    /// # use extremedb::runtime::{Runtime, TxManager};
+    /// # fn main() -> extremedb::Result<()> {
+    /// # let runtime = Runtime::start(vec![]);
+    /// let devs = vec![Device::new_mem_conv(Assignment::Database, 1024 * 1024)?];
+    /// let mvcc = runtime.info().transaction_manager() == Some(TxManager::Mvcc);
+    ///
+    /// let mut params = Params::new();
+    /// params.mem_page_size(256);
+    ///
+    /// // The default size is accepted.
+    /// assert!(params.check_mvcc_bitmap_size(&devs).is_ok());
+    ///
+    /// // 4096 pages require a bitmap of at least 512 bytes.
+    /// params.mvcc_bitmap_size(16);
+    /// assert_eq!(params.check_mvcc_bitmap_size(&devs).is_err(), mvcc);
+    ///
+    /// params.mvcc_bitmap_size(1024);
+    /// assert!(params.check_mvcc_bitmap_size(&devs).is_ok());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn check_mvcc_bitmap_size(&self, devs: &[Device]) -> Result<()> {
+        if Runtime::info_impl().transaction_manager() != Some(TxManager::Mvcc)
+            || self.p.mvcc_bitmap_size == 0
+        {
+            return Ok(());
+        }
+
+        let pages = Params::database_memory_size(devs)
+            .checked_div(self.p.mem_page_size as exdb_sys::mco_size_t)
+            .unwrap_or(0);
+        let min_bitmap_size = pages / 8;
+
+        // The setter accepts u32 values which may overflow the C int.
+        if self.p.mvcc_bitmap_size < 0
+            || (self.p.mvcc_bitmap_size as exdb_sys::mco_size_t) < min_bitmap_size
+        {
+            return Err(Error::new_core(mco_ret::MCO_E_ILLEGAL_PARAM));
+        }
+
+        Ok(())
+    }
+
+    fn database_memory_size(devs: &[Device]) -> exdb_sys::mco_size_t {
+        devs.iter().map(|d| d.database_memory_size()).sum()
+    }
 }
 
 impl Drop for Params {
@@ -1136,6 +1338,38 @@ impl Drop for Params {
     }
 }
 
+/// Database memory usage information.
+///
+/// This structure describes the usage of the in-memory portion of the
+/// database. All sizes are in bytes.
+pub struct MemInfo {
+    page_size: usize,
+    total_pages: u32,
+    free_pages: u32,
+}
+
+impl MemInfo {
+    /// Returns the memory page size.
+    pub fn page_size(&self) -> usize {
+        self.page_size
+    }
+
+    /// Returns the total size of the database memory.
+    pub fn total(&self) -> usize {
+        self.total_pages as usize * self.page_size
+    }
+
+    /// Returns the size of the memory used by the database.
+    pub fn used(&self) -> usize {
+        self.total() - self.free()
+    }
+
+    /// Returns the size of the free memory.
+    pub fn free(&self) -> usize {
+        self.free_pages as usize * self.page_size
+    }
+}
+
 /// A database instance.
 ///
 /// A database instance cannot be used directly to manipulate the database
@@ -1147,6 +1381,13 @@ pub struct Database<'a> {
     runtime: PhantomData<&'a Runtime>,
     devices: PhantomData<&'a mut Vec<Device>>, // Devices are mutated within eXtremeDB code
     name: CString,
+    mem_page_size: u16,
+    disk_page_size: u32,
+    mode_mask: i32,
+    db_log_type: mco_log_type::Type,
+    attached: bool,
+    connections: Cell<usize>,
+    schema_version: AtomicU64,
 }
 
 impl<'a> Database<'a> {
@@ -1156,6 +1397,69 @@ impl<'a> Database<'a> {
     /// will be rejected.
     ///
     /// `dict` is not currently used and must be set to `None`.
+    ///
+    /// # Errors
+    ///
+    /// If the CRC check of a persistent database fails when it is opened,
+    /// this method returns an error for which [`Error::is_crc_mismatch()`]
+    /// returns `true`. The runtime's fatal CRC errors are reported the same
+    /// way. The most likely cause is a database written using a different
+    /// CRC algorithm; compare the algorithm used to write the database
+    /// with [`Runtime::crc_algorithm()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use extremedb::database::{self, ModeMask};
+    /// # use extremedb::device::{util, Assignment, Device, FileOpenFlags};
+    /// # use extremedb::runtime::options::{CRCAlgorithm, Opt};
+    /// # use extremedb::runtime::Runtime;
+    /// # use std::fs;
+    /// # fn main() -> extremedb::Result<()> {
+    ///     let runtime = Runtime::start(vec![Opt::CRCAlgorithm(CRCAlgorithm::CRC32C)]);
+    /// #     if !runtime.info().disk_supported() {
+    /// #         return Ok(());
+    /// #     }
+    ///     let db_file = "crc.dbs";
+    ///     let log_file = "crc.log";
+    /// #     let _ = fs::remove_file(db_file);
+    /// #     let _ = fs::remove_file(log_file);
+    ///
+    ///     let mut mode = ModeMask::new();
+    ///     mode.use_crc_check(true);
+    ///
+    ///     let mut devs = vec![
+    ///         util::new_test_mem_dev(Assignment::Database),
+    ///         util::new_test_mem_dev(Assignment::Cache),
+    ///         Device::new_file(Assignment::Persistent, FileOpenFlags::new(), db_file)?,
+    ///         Device::new_file(Assignment::Log, FileOpenFlags::new(), log_file)?,
+    ///     ];
+    ///
+    ///     let mut params = database::Params::new();
+    ///     params.mode_mask(mode);
+    ///
+    ///     match database::Database::open(&runtime, "test_db", None, &mut devs, params) {
+    ///         Err(e) if e.is_crc_mismatch() => {
+    ///             panic!(
+    ///                 "database was not written using {:?}",
+    ///                 runtime.crc_algorithm()
+    ///             );
+    ///         }
+    ///         res => {
+    ///             let db = res?;
+    /// #             drop(db);
+    ///         }
+    ///     }
+    /// #
+    /// #     drop(devs);
+    /// #     let _ = fs::remove_file(db_file);
+    /// #     let _ = fs::remove_file(log_file);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Error::is_crc_mismatch()`]: ../enum.Error.html#method.is_crc_mismatch
+    /// [`Runtime::crc_algorithm()`]: ../runtime/struct.Runtime.html#method.crc_algorithm
     pub fn open(
         _runtime: &'a Runtime,
         name: &str,
@@ -1168,6 +1472,10 @@ impl<'a> Database<'a> {
         }
 
         let cname = CString::new(name).unwrap();
+        let mem_page_size = params.get_mem_page_size();
+        let disk_page_size = params.get_disk_page_size();
+        let mode_mask = params.p.mode_mask;
+        let db_log_type = params.p.db_log_type;
         let mut params = params;
         let dict_p = match dict {
             Some(d) => &d.nested as *const exdb_sys::mco_dictionary_t,
@@ -1182,15 +1490,115 @@ impl<'a> Database<'a> {
                 devs.len() as exdb_sys::mco_size_t,
                 &mut params.p,
             )
-        })?;
+        })
+        .map_err(Database::clarify_open_error)?;
 
         Ok(Database {
             runtime: PhantomData,
             devices: PhantomData,
             name: cname,
+            mem_page_size,
+            disk_page_size,
+            mode_mask,
+            db_log_type,
+            attached: false,
+            connections: Cell::new(0),
+            schema_version: AtomicU64::new(0),
         })
     }
 
+    /// Attaches to a shared memory database instance opened by another
+    /// process.
+    ///
+    /// Unlike [`open()`], this method does not open the database: it only
+    /// verifies that a database named `name` can be connected to, and
+    /// returns an instance which can be used to create [`Connection`]s.
+    /// The device layout of the database does not have to be re-specified.
+    ///
+    /// This method is only applicable to the shared memory runtime. It
+    /// returns `MCO_E_ILLEGAL_PARAM` if the runtime does not support
+    /// multiprocess access, and an error reported by the runtime if the
+    /// database does not exist.
+    ///
+    /// The database is not closed when the returned instance is dropped;
+    /// this remains the responsibility of the process that opened it.
+    ///
+    /// `name` must be an ASCII string without NUL characters; other strings
+    /// will be rejected with `MCO_E_ILLEGAL_PARAM`.
+    ///
+    /// # Examples
+    ///
+    /// The database is opened by this process, and attached to by a child
+    /// process:
+    ///
+    /// ```
+    /// # use extremedb::{connection, database, runtime, Result};
+    /// # use extremedb::device::util;
+    /// # use std::{env, process};
+    /// # fn main() -> Result<()> {
+    ///     let runtime = runtime::Runtime::start(vec![]);
+    /// #     if !runtime.info().multiprocess_access_supported() {
+    /// #         return Ok(());
+    /// #     }
+    ///
+    ///     if env::var_os("EXDB_ATTACH_TEST_CHILD").is_some() {
+    ///         // Second process
+    ///         let attached = database::Database::attach(&runtime, "attach_db")?;
+    ///         let conn = connection::Connection::new(&attached)?;
+    ///         drop(conn);
+    ///         process::exit(0);
+    ///     }
+    ///
+    ///     // First process
+    ///     let mut devs = util::DeviceContainer::new();
+    ///     let db = database::Database::open(
+    ///         &runtime,
+    ///         "attach_db",
+    ///         None,
+    ///         devs.devices(),
+    ///         database::Params::new(),
+    ///     )?;
+    ///
+    ///     let status = process::Command::new(env::current_exe().unwrap())
+    ///         .env("EXDB_ATTACH_TEST_CHILD", "1")
+    ///         .status()
+    ///         .unwrap();
+    ///     assert!(status.success());
+    /// #
+    /// #     drop(db);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`open()`]: #method.open
+    /// [`Connection`]: ../connection/struct.Connection.html
+    pub fn attach(runtime: &'a Runtime, name: &str) -> Result<Self> {
+        if !name.is_ascii() || !runtime.info().multiprocess_access_supported() {
+            return Err(Error::new_core(mco_ret::MCO_E_ILLEGAL_PARAM));
+        }
+
+        let cname =
+            CString::new(name).map_err(|_| Error::new_core(mco_ret::MCO_E_ILLEGAL_PARAM))?;
+
+        let db = Database {
+            runtime: PhantomData,
+            devices: PhantomData,
+            name: cname,
+            mem_page_size: 0,
+            disk_page_size: 0,
+            mode_mask: 0,
+            db_log_type: mco_log_type::NO_LOG,
+            attached: true,
+            connections: Cell::new(0),
+            schema_version: AtomicU64::new(0),
+        };
+
+        // Fails if the database does not exist.
+        db.with_connection(|_| Ok(()))?;
+
+        Ok(db)
+    }
+
     /// Removes a shared memory segment associated with a database.
     ///
     /// Also removes `name` from the registry.
@@ -1214,11 +1622,274 @@ impl<'a> Database<'a> {
     pub fn name(&self) -> &CStr {
         &self.name
     }
+
+    /// Returns the memory usage information for the in-memory portion of
+    /// the database.
+    ///
+    /// This information can be used to detect under- or over-provisioning
+    /// of the database memory devices.
+    ///
+    /// The memory page size is not known for the attached databases;
+    /// `MCO_E_UNSUPPORTED` is returned for them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use extremedb::sql::engine::Engine;
+    /// # use extremedb::{connection, database, device, runtime, sql};
+    /// # use extremedb::device::util;
+    /// # fn main() -> extremedb::Result<()> {
+    /// #     let runtime = runtime::Runtime::start(vec![]);
+    /// #     let mut db_params = database::Params::new();
+    /// #     db_params
+    /// #         .ddl_dict_size(32768)
+    /// #         .max_classes(100)
+    /// #         .max_indexes(1000);
+    /// #     let mut devs = util::DeviceContainer::new();
+    /// #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+    /// #     let conn = connection::Connection::new(&db)?;
+    /// #     let engine = sql::engine::LocalEngine::new(&conn)?;
+    ///     engine.execute_statement("CREATE TABLE TestTable(i integer, s string);", &[])?;
+    ///
+    ///     let before = db.memory_info()?;
+    ///
+    ///     for i in 0..1000 {
+    ///         engine.execute_statement("INSERT INTO TestTable VALUES(?, ?);", &[&i, &"Hello"])?;
+    ///     }
+    ///
+    ///     let after = db.memory_info()?;
+    ///
+    ///     assert!(after.used() > before.used());
+    ///     assert!(after.used() <= after.total());
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn memory_info(&self) -> Result<MemInfo> {
+        if self.attached {
+            return Err(Error::new_core(mco_ret::MCO_E_UNSUPPORTED));
+        }
+
+        self.with_connection(|h| {
+            let mut total_pages = 0;
+            let mut free_pages = 0;
+
+            result_from_code(unsafe { exdb_sys::mco_db_total_pages(h, &mut total_pages) })?;
+            result_from_code(unsafe { exdb_sys::mco_db_free_pages(h, &mut free_pages) })?;
+
+            Ok(MemInfo {
+                page_size: self.mem_page_size as usize,
+                total_pages,
+                free_pages,
+            })
+        })
+    }
+
+    /// Returns the parameters reflecting the configuration of this
+    /// database.
+    ///
+    /// The returned parameters can be modified and used to open another
+    /// database with a similar configuration. The *e*X*treme*DB runtime
+    /// does not report the database configuration; only the following
+    /// parameters are recovered from the values this database was opened
+    /// with:
+    ///
+    /// * memory page size;
+    /// * persistent storage page size;
+    /// * mode mask;
+    /// * database log type.
+    ///
+    /// All other parameters, including the cipher and license keys, have
+    /// their default values.
+    ///
+    /// The parameters are not known for the attached databases;
+    /// `MCO_E_UNSUPPORTED` is returned for them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use extremedb::{database, runtime};
+    /// # use extremedb::device::util;
+    /// # fn main() -> extremedb::Result<()> {
+    /// #     let runtime = runtime::Runtime::start(vec![]);
+    ///     let mut db_params = database::Params::new();
+    ///     db_params.mem_page_size(512).disk_page_size(8192);
+    ///
+    ///     let mut devs = util::DeviceContainer::new();
+    ///     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+    ///
+    ///     let params = db.params()?;
+    ///     assert_eq!(params.get_mem_page_size(), 512);
+    ///     assert_eq!(params.get_disk_page_size(), 8192);
+    ///
+    ///     drop(db);
+    ///
+    ///     let mut devs = util::DeviceContainer::new();
+    ///     let clone = database::Database::open(&runtime, "test_db_clone", None, devs.devices(), params)?;
+    ///     assert_eq!(clone.params()?.get_mem_page_size(), 512);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn params(&self) -> Result<Params> {
+        if self.attached {
+            return Err(Error::new_core(mco_ret::MCO_E_UNSUPPORTED));
+        }
+
+        let mut params = Params::new();
+        params
+            .mem_page_size(self.mem_page_size)
+            .disk_page_size(self.disk_page_size);
+        params.p.mode_mask = self.mode_mask;
+        params.p.db_log_type = self.db_log_type;
+
+        Ok(params)
+    }
+
+    /// Returns the number of [`Connection`]s created for this database
+    /// instance which are currently open.
+    ///
+    /// The count is maintained locally by this crate, rather than queried
+    /// from the *e*X*treme*DB runtime. The connections established by
+    /// other database instances or, in a multiprocess (shared memory)
+    /// configuration, by the other processes are not included; the runtime
+    /// itself will refuse to close a database with open connections,
+    /// returning `MCO_E_OPENED_SESSIONS`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use extremedb::{connection, database, runtime};
+    /// # use extremedb::device::util;
+    /// # fn main() -> extremedb::Result<()> {
+    /// #     let runtime = runtime::Runtime::start(vec![]);
+    /// #     let mut devs = util::DeviceContainer::new();
+    ///     let db = database::Database::open(
+    ///         &runtime,
+    ///         "test_db",
+    ///         None,
+    ///         devs.devices(),
+    ///         database::Params::new(),
+    ///     )?;
+    ///     assert_eq!(db.local_connection_count(), 0);
+    ///
+    ///     let conn1 = connection::Connection::new(&db)?;
+    ///     let conn2 = connection::Connection::new(&db)?;
+    ///     let conn3 = connection::Connection::new(&db)?;
+    ///     assert_eq!(db.local_connection_count(), 3);
+    ///
+    ///     drop(conn1);
+    ///     drop(conn2);
+    ///     assert_eq!(db.local_connection_count(), 1);
+    ///
+    ///     drop(conn3);
+    ///     assert_eq!(db.local_connection_count(), 0);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Connection`]: ../connection/struct.Connection.html
+    pub fn local_connection_count(&self) -> usize {
+        self.connections.get()
+    }
+
+    /// Returns the current schema version of the database.
+    ///
+    /// The version starts at zero when the database instance is opened or
+    /// attached, and is incremented every time a DDL statement (`CREATE`,
+    /// `DROP`, or `ALTER`) is successfully executed by a local SQL engine or
+    /// session created for this database instance. Applications can compare
+    /// the versions to detect that cached schema-dependent state (such as
+    /// prepared SQL text or table metadata) has to be refreshed.
+    ///
+    /// The *e*X*treme*DB core does not expose a persistent dictionary
+    /// version, so the schema changes made by the other processes, or
+    /// through the remote SQL servers, are not reflected in the returned
+    /// value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use extremedb::sql::engine::{Engine, LocalEngine};
+    /// # use extremedb::{connection, database, runtime};
+    /// # use extremedb::device::util;
+    /// # fn main() -> extremedb::Result<()> {
+    /// #     let runtime = runtime::Runtime::start(vec![]);
+    /// #     let mut db_params = database::Params::new();
+    /// #     db_params
+    /// #         .ddl_dict_size(32768)
+    /// #         .max_classes(100)
+    /// #         .max_indexes(1000);
+    /// #     let mut devs = util::DeviceContainer::new();
+    /// #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+    ///     let conn1 = connection::Connection::new(&db)?;
+    ///     let conn2 = connection::Connection::new(&db)?;
+    ///     let engine1 = LocalEngine::new(&conn1)?;
+    ///     let engine2 = LocalEngine::new(&conn2)?;
+    ///
+    ///     engine1.execute_statement("CREATE TABLE TestTable(i integer);", &[])?;
+    ///     let before = db.schema_version()?;
+    ///
+    ///     engine2.execute_statement("INSERT INTO TestTable(i) VALUES(1);", &[])?;
+    ///     assert_eq!(db.schema_version()?, before);
+    ///
+    ///     engine2.execute_statement("CREATE TABLE OtherTable(s string);", &[])?;
+    ///     assert!(db.schema_version()? > before);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn schema_version(&self) -> Result<u64> {
+        Ok(self.schema_version.load(Ordering::Acquire))
+    }
+
+    #[cfg(feature = "sql")]
+    pub(crate) fn schema_version_slot(&self) -> &AtomicU64 {
+        &self.schema_version
+    }
+
+    pub(crate) fn connection_opened(&self) {
+        self.connections.set(self.connections.get() + 1);
+    }
+
+    pub(crate) fn connection_closed(&self) {
+        debug_assert!(self.connections.get() > 0);
+        self.connections.set(self.connections.get() - 1);
+    }
+
+    // Runs f using a temporary connection to the database.
+    fn with_connection<T, F>(&self, f: F) -> Result<T>
+    where
+        F: FnOnce(exdb_sys::mco_db_h) -> Result<T>,
+    {
+        let mut h = MaybeUninit::uninit();
+        result_from_code(unsafe { exdb_sys::mco_db_connect(self.name.as_ptr(), h.as_mut_ptr()) })?;
+        let h = unsafe { h.assume_init() };
+
+        let ret = f(h);
+
+        let rc = unsafe { exdb_sys::mco_db_disconnect(h) };
+        debug_assert_eq!(mco_ret::MCO_S_OK, rc);
+
+        ret
+    }
+
+    // Reports the fatal CRC errors (MCO_ERR_DISK_CRC + line) as CRC mismatches.
+    fn clarify_open_error(e: Error) -> Error {
+        match e {
+            Error::Core(ref ce)
+                if ce.code() >= mco_ret::MCO_ERR_DISK_CRC
+                    && ce.code() < mco_ret::MCO_ERR_DISK_FORMAT_MISMATCH =>
+            {
+                Error::new_core(mco_ret::MCO_E_DISK_CRC_MISMATCH)
+            }
+            e => e,
+        }
+    }
 }
 
 impl<'a> Drop for Database<'a> {
     fn drop(&mut self) {
-        let rc = unsafe { exdb_sys::mco_db_close(self.name.as_ptr()) };
-        debug_assert_eq!(mco_ret::MCO_S_OK, rc);
+        if !self.attached {
+            let rc = unsafe { exdb_sys::mco_db_close(self.name.as_ptr()) };
+            debug_assert_eq!(mco_ret::MCO_S_OK, rc);
+        }
     }
 }
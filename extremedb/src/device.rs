@@ -180,6 +180,7 @@ macro_rules! file_open_flags_builder_method {
 /// for most applications.
 ///
 /// [`new()`]: #method.new
+#[derive(Clone, Copy)]
 pub struct FileOpenFlags(u32);
 
 impl FileOpenFlags {
@@ -331,7 +332,84 @@ impl Device {
     }
 
     /// Creates a new file device.
+    ///
+    /// The device size is set to zero; use [`new_file_sized()`] to specify
+    /// it explicitly.
+    ///
+    /// [`new_file_sized()`]: #method.new_file_sized
     pub fn new_file(a: Assignment, flags: FileOpenFlags, name: &str) -> Result<Self> {
+        Device::new_file_sized(a, flags, name, 0)
+    }
+
+    /// Creates a new file device of the given `size`, in bytes.
+    ///
+    /// The size is passed to the *e*X*treme*DB runtime in the device
+    /// descriptor, and the file is allocated to at least `size` bytes when
+    /// the database is opened. Zero means that the size is not specified,
+    /// and the file grows as needed.
+    ///
+    /// The device size does not replace the database parameters which
+    /// control the growth of the database file: the file is still extended
+    /// in increments of [`Params::file_extension_quantum()`], up to
+    /// [`Params::disk_max_database_size()`], if set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use extremedb::device::{util, Assignment, Device, FileOpenFlags};
+    /// # use extremedb::{database, runtime};
+    /// # use std::fs;
+    /// # fn main() -> extremedb::Result<()> {
+    /// #     let runtime = runtime::Runtime::start(vec![]);
+    /// #     if !runtime.info().disk_supported() {
+    /// #         return Ok(());
+    /// #     }
+    ///     const FILE_SIZE: usize = 16 * 1024 * 1024;
+    ///
+    ///     let db_file = "sized.dbs";
+    ///     let log_file = "sized.log";
+    /// #     let _ = fs::remove_file(db_file);
+    /// #     let _ = fs::remove_file(log_file);
+    ///
+    ///     let mut devs = vec![
+    ///         util::new_test_mem_dev(Assignment::Database),
+    ///         util::new_test_mem_dev(Assignment::Cache),
+    ///         Device::new_file_sized(
+    ///             Assignment::Persistent,
+    ///             FileOpenFlags::new(),
+    ///             db_file,
+    ///             FILE_SIZE,
+    ///         )?,
+    ///         Device::new_file(Assignment::Log, FileOpenFlags::new(), log_file)?,
+    ///     ];
+    ///     assert_eq!(devs[2].size(), FILE_SIZE);
+    ///
+    ///     let db = database::Database::open(
+    ///         &runtime,
+    ///         "test_db",
+    ///         None,
+    ///         &mut devs,
+    ///         database::Params::new(),
+    ///     )?;
+    ///
+    ///     assert!(fs::metadata(db_file).unwrap().len() >= FILE_SIZE as u64);
+    /// #
+    /// #     drop(db);
+    /// #     drop(devs);
+    /// #     let _ = fs::remove_file(db_file);
+    /// #     let _ = fs::remove_file(log_file);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Params::file_extension_quantum()`]: ../database/struct.Params.html#method.file_extension_quantum
+    /// [`Params::disk_max_database_size()`]: ../database/struct.Params.html#method.disk_max_database_size
+    pub fn new_file_sized(
+        a: Assignment,
+        flags: FileOpenFlags,
+        name: &str,
+        size: usize,
+    ) -> Result<Self> {
         let mut file = unsafe { mem::zeroed::<McoDeviceTypeFile>() };
 
         if name.len() >= file.name.len() {
@@ -347,7 +425,7 @@ impl Device {
         Ok(Device(exdb_sys::mco_device_t {
             type_: mco_dev_type::MCO_MEMORY_FILE,
             assignment: a.to_mco(),
-            size: 0,
+            size: size as exdb_sys::mco_size_t,
             dev: McoDeviceTypeUnion { file },
         }))
     }
@@ -414,6 +492,92 @@ impl Device {
         }))
     }
 
+    /// Returns the size of the device, in bytes.
+    pub fn size(&self) -> usize {
+        self.0.size
+    }
+
+    /// Returns the segment size of a multi-file device.
+    ///
+    /// Returns `None` if this is not a multi-file device.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use extremedb::device::{Assignment, Device, FileOpenFlags};
+    /// # fn main() -> extremedb::Result<()> {
+    /// let dev = Device::new_multifile(
+    ///     Assignment::Persistent,
+    ///     FileOpenFlags::new(),
+    ///     "db.dbs",
+    ///     1024 * 1024,
+    /// )?;
+    ///
+    /// assert_eq!(dev.multifile_segment_size(), Some(1024 * 1024));
+    /// assert_eq!(dev.raid_level(), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn multifile_segment_size(&self) -> Option<isize> {
+        match self.0.type_ {
+            mco_dev_type::MCO_MEMORY_MULTIFILE => {
+                Some(unsafe { self.0.dev.multifile.segment_size } as isize)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the level of a RAID device.
+    ///
+    /// Returns `None` if this is not a RAID device.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use extremedb::device::{Assignment, Device, FileOpenFlags};
+    /// # fn main() -> extremedb::Result<()> {
+    /// let dev = Device::new_raid(
+    ///     Assignment::Persistent,
+    ///     FileOpenFlags::new(),
+    ///     "db.dbs",
+    ///     1,
+    ///     4096,
+    /// )?;
+    ///
+    /// assert_eq!(dev.raid_level(), Some(1));
+    /// assert_eq!(dev.raid_offset(), Some(4096));
+    /// assert_eq!(dev.multifile_segment_size(), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn raid_level(&self) -> Option<i32> {
+        match self.0.type_ {
+            mco_dev_type::MCO_MEMORY_RAID => Some(unsafe { self.0.dev.raid.level }),
+            _ => None,
+        }
+    }
+
+    /// Returns the offset of a RAID device.
+    ///
+    /// Returns `None` if this is not a RAID device.
+    pub fn raid_offset(&self) -> Option<isize> {
+        match self.0.type_ {
+            mco_dev_type::MCO_MEMORY_RAID => Some(unsafe { self.0.dev.raid.offset } as isize),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn database_memory_size(&self) -> exdb_sys::mco_size_t {
+        let is_mem = self.0.type_ == mco_dev_type::MCO_MEMORY_CONV
+            || self.0.type_ == mco_dev_type::MCO_MEMORY_NAMED;
+
+        if is_mem && self.0.assignment == Assignment::Database.to_mco() {
+            self.0.size
+        } else {
+            0
+        }
+    }
+
     fn file_name(&self) -> Option<&str> {
         match self.0.type_ {
             mco_dev_type::MCO_MEMORY_FILE => {
@@ -16,7 +16,7 @@ use std::fs;
 use std::path::Path;
 
 use crate::device::{Assignment, Device, FileOpenFlags, NamedMemFlags};
-use crate::{runtime::Runtime, Result};
+use crate::{mco_ret, runtime::Runtime, Error, Result};
 
 #[cfg(target_os = "macos")]
 const MCO_DATABASE_DEFAULT_MAP_ADDRESS: usize = 0x2_0000_0000;
@@ -101,6 +101,96 @@ pub fn new_mem_dev(a: Assignment, s: usize, name: &str) -> Result<Device> {
     }
 }
 
+/// Creates the standard set of devices for a persistent database.
+///
+/// The returned vector contains, in order: the in-memory database device of
+/// `mem_size` bytes, the disk page cache device of `cache_size` bytes, the
+/// data file device at `data_path`, and the log file device at `log_path`.
+/// Both file devices are opened with the given `flags`. The memory devices
+/// are created by [`new_mem_dev()`], with the names inferred from the
+/// executable name.
+///
+/// Returns `MCO_E_ILLEGAL_PARAM` if any of the sizes is zero, or any of the
+/// paths is empty or too long for a file device.
+///
+/// # Examples
+///
+/// ```
+/// # use extremedb::device::{util, FileOpenFlags};
+/// # use extremedb::sql::engine::{Engine, LocalEngine};
+/// # use extremedb::{connection, database, runtime};
+/// # use std::fs;
+/// # fn main() -> extremedb::Result<()> {
+/// #     let runtime = runtime::Runtime::start(vec![]);
+/// #     if !runtime.info().disk_supported() {
+/// #         return Ok(());
+/// #     }
+///     let db_file = "persistent_set.dbs";
+///     let log_file = "persistent_set.log";
+/// #     let _ = fs::remove_file(db_file);
+/// #     let _ = fs::remove_file(log_file);
+///
+///     let mut devs = util::persistent_set(
+///         1024 * 1024,
+///         1024 * 1024,
+///         db_file,
+///         log_file,
+///         FileOpenFlags::new(),
+///     )?;
+///     assert_eq!(devs.len(), 4);
+///
+///     let mut db_params = database::Params::new();
+///     db_params
+///         .ddl_dict_size(32768)
+///         .max_classes(100)
+///         .max_indexes(1000);
+///
+///     let db = database::Database::open(&runtime, "test_db", None, &mut devs, db_params)?;
+///     let conn = connection::Connection::new(&db)?;
+///     let engine = LocalEngine::new(&conn)?;
+///
+///     engine.execute_statement("CREATE TABLE TestTable(i integer);", &[])?;
+///     engine.execute_statement("INSERT INTO TestTable(i) VALUES(?);", &[&1])?;
+///     assert_eq!(engine.query_scalar_opt::<i64>("SELECT COUNT(*) FROM TestTable;", &[])?, Some(1));
+/// #
+/// #     drop(engine);
+/// #     drop(conn);
+/// #     drop(db);
+/// #     drop(devs);
+/// #     let _ = fs::remove_file(db_file);
+/// #     let _ = fs::remove_file(log_file);
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [`new_mem_dev()`]: ./fn.new_mem_dev.html
+pub fn persistent_set(
+    mem_size: usize,
+    cache_size: usize,
+    data_path: &str,
+    log_path: &str,
+    flags: FileOpenFlags,
+) -> Result<Vec<Device>> {
+    if mem_size == 0 || cache_size == 0 || data_path.is_empty() || log_path.is_empty() {
+        return Err(Error::new_core(mco_ret::MCO_E_ILLEGAL_PARAM));
+    }
+
+    Ok(vec![
+        new_mem_dev(
+            Assignment::Database,
+            mem_size,
+            &mem_dev_name(Assignment::Database),
+        )?,
+        new_mem_dev(
+            Assignment::Cache,
+            cache_size,
+            &mem_dev_name(Assignment::Cache),
+        )?,
+        Device::new_file(Assignment::Persistent, flags, data_path)?,
+        Device::new_file(Assignment::Log, flags, log_path)?,
+    ])
+}
+
 /// Creates a new memory device which must be suitable for most doctests.
 ///
 /// For named devices, the name is inferred from the executable name.
@@ -30,6 +30,18 @@
 //! - **`sql`** — SQL engine.
 //! - **`rsql`** — Remote SQL engine (SQL server and client).
 //! - **`sequences`** — Sequences (vertical storage).
+//! - **`serde_json`** — Decoding of the JSON documents stored in SQL strings,
+//!   and conversion of SQL values to JSON.
+//! - **`chrono`** — Conversions between the SQL timestamps and
+//!   `chrono::DateTime<Utc>`.
+//! - **`time`** — Conversions between the SQL timestamps and
+//!   `time::OffsetDateTime`.
+//! - **`rust_decimal`** — Conversions between the SQL numerics and
+//!   `rust_decimal::Decimal`.
+//! - **`derive`** — `#[derive(FromRow)]` and `#[derive(ToRow)]` macros for
+//!   reading SQL records into structures and inserting structures as rows.
+//! - **`tokio`** — Asynchronous SQL engine wrapper running on the Tokio
+//!   blocking thread pool.
 //!
 //! # SQL Example
 //!
@@ -207,6 +219,10 @@ pub enum Error {
     /// An SQL API error.
     #[cfg(feature = "sql")]
     Sql(SqlError),
+
+    /// A JSON decoding error.
+    #[cfg(feature = "serde_json")]
+    Json(serde_json::Error),
 }
 
 impl Error {
@@ -218,6 +234,287 @@ impl Error {
     pub(crate) fn new_sql(rc: McoSqlStatusCode) -> Self {
         Error::Sql(SqlError::new(rc))
     }
+
+    /// Returns `true` if this error was caused by a change of the database
+    /// schema.
+    ///
+    /// Such errors are reported as [`Error::Core`] with either the
+    /// `MCO_E_SCHEMA_CHANGED` or the `MCO_E_DISK_SCHEMA_CHANGED` code. For
+    /// instance, an SQL cursor fails with this error if the schema was
+    /// altered by another connection while the cursor was open. The
+    /// operation can be retried after re-executing the query.
+    ///
+    /// [`Error::Core`]: ./enum.Error.html#variant.Core
+    pub fn is_schema_changed(&self) -> bool {
+        match self {
+            Error::Core(e) => matches!(
+                e.code(),
+                mco_ret::MCO_E_SCHEMA_CHANGED | mco_ret::MCO_E_DISK_SCHEMA_CHANGED
+            ),
+
+            #[cfg(feature = "sql")]
+            Error::Sql(_) => false,
+
+            #[cfg(feature = "serde_json")]
+            Error::Json(_) => false,
+        }
+    }
+
+    /// Returns `true` if this error was caused by a CRC mismatch in a
+    /// persistent database.
+    ///
+    /// Such errors are reported as [`Error::Core`] with the
+    /// `MCO_E_DISK_CRC_MISMATCH` code. When returned by
+    /// [`Database::open()`], this error usually means that the database was
+    /// written using a CRC algorithm different from the one used by the
+    /// runtime (see [`Runtime::crc_algorithm()`]), rather than that the
+    /// database is corrupted.
+    ///
+    /// # Examples
+    ///
+    /// Writing a persistent database using one CRC algorithm, and opening it
+    /// using another. The runtime cannot be restarted in the same process,
+    /// so the database is reopened by a child process.
+    ///
+    /// ```
+    /// # use extremedb::database::{self, ModeMask};
+    /// # use extremedb::device::{util, Assignment, Device, FileOpenFlags};
+    /// # use extremedb::runtime::options::{CRCAlgorithm, Opt};
+    /// # use extremedb::runtime::Runtime;
+    /// # use std::{env, fs, process};
+    /// # fn main() -> extremedb::Result<()> {
+    ///     let child = env::var_os("EXDB_CRC_TEST_CHILD").is_some();
+    ///     let algorithm = if child {
+    ///         CRCAlgorithm::CRC32
+    ///     } else {
+    ///         CRCAlgorithm::CRC32C
+    ///     };
+    ///
+    ///     let runtime = Runtime::start(vec![Opt::CRCAlgorithm(algorithm)]);
+    /// #     if !runtime.info().disk_supported() {
+    /// #         return Ok(());
+    /// #     }
+    ///     let db_file = "crc_mismatch.dbs";
+    ///     let log_file = "crc_mismatch.log";
+    ///     if !child {
+    ///         let _ = fs::remove_file(db_file);
+    ///         let _ = fs::remove_file(log_file);
+    ///     }
+    ///
+    ///     let mut mode = ModeMask::new();
+    ///     mode.use_crc_check(true);
+    ///
+    ///     let mut devs = vec![
+    ///         util::new_test_mem_dev(Assignment::Database),
+    ///         util::new_test_mem_dev(Assignment::Cache),
+    ///         Device::new_file(Assignment::Persistent, FileOpenFlags::new(), db_file)?,
+    ///         Device::new_file(Assignment::Log, FileOpenFlags::new(), log_file)?,
+    ///     ];
+    ///
+    ///     let mut params = database::Params::new();
+    ///     params.mode_mask(mode);
+    ///
+    ///     let res = database::Database::open(&runtime, "test_db", None, &mut devs, params);
+    ///
+    ///     if child {
+    ///         let mismatch = matches!(res, Err(ref e) if e.is_crc_mismatch());
+    ///         process::exit(if mismatch { 0 } else { 1 });
+    ///     }
+    ///
+    ///     drop(res?);
+    ///     drop(devs);
+    ///
+    ///     let status = process::Command::new(env::current_exe().unwrap())
+    ///         .env("EXDB_CRC_TEST_CHILD", "1")
+    ///         .status()
+    ///         .unwrap();
+    ///     assert!(status.success());
+    /// #
+    /// #     let _ = fs::remove_file(db_file);
+    /// #     let _ = fs::remove_file(log_file);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Error::Core`]: ./enum.Error.html#variant.Core
+    /// [`Database::open()`]: ./database/struct.Database.html#method.open
+    /// [`Runtime::crc_algorithm()`]: ./runtime/struct.Runtime.html#method.crc_algorithm
+    pub fn is_crc_mismatch(&self) -> bool {
+        match self {
+            Error::Core(e) => e.code() == mco_ret::MCO_E_DISK_CRC_MISMATCH,
+
+            #[cfg(feature = "sql")]
+            Error::Sql(_) => false,
+
+            #[cfg(feature = "serde_json")]
+            Error::Json(_) => false,
+        }
+    }
+
+    /// Returns `true` if this error was caused by a violation of a unique
+    /// constraint, such as an attempt to insert a duplicate primary key.
+    ///
+    /// The SQL engine reports such errors as [`Error::Sql`] with the
+    /// `NOT_UNIQUE` code; the core API reports them as [`Error::Core`] with
+    /// the `MCO_S_DUPLICATE` code. Transaction conflicts (`SQL_CONFLICT`,
+    /// `MCO_E_CONFLICT`) are not considered unique constraint violations:
+    /// the conflicting transaction has to be retried instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use extremedb::sql::engine::Engine;
+    /// # use extremedb::{connection, database, runtime, sql};
+    /// # use extremedb::device::util;
+    /// # fn main() -> extremedb::Result<()> {
+    /// #     let runtime = runtime::Runtime::start(vec![]);
+    /// #     let mut db_params = database::Params::new();
+    /// #     db_params
+    /// #         .ddl_dict_size(32768)
+    /// #         .max_classes(100)
+    /// #         .max_indexes(1000);
+    /// #     let mut devs = util::DeviceContainer::new();
+    /// #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+    /// #     let conn = connection::Connection::new(&db)?;
+    /// #     let engine = sql::engine::LocalEngine::new(&conn)?;
+    ///     engine.execute_statement("CREATE TABLE TestTable(i integer primary key, s string);", &[])?;
+    ///
+    ///     let insert = "INSERT INTO TestTable(i, s) VALUES(?, ?);";
+    ///     engine.execute_statement(insert, &[&1, &"Hello"])?;
+    ///
+    ///     // Duplicate key: update the existing record instead.
+    ///     let err = engine
+    ///         .execute_statement(insert, &[&1, &"World"])
+    ///         .unwrap_err();
+    ///     assert!(err.is_unique_violation());
+    ///     engine.execute_statement("UPDATE TestTable SET s = ? WHERE i = ?;", &[&"World", &1])?;
+    ///
+    ///     // Other errors are not unique violations.
+    ///     let err = engine
+    ///         .execute_statement("INSERT INTO MissingTable(i) VALUES(?);", &[&1])
+    ///         .unwrap_err();
+    ///     assert!(!err.is_unique_violation());
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Error::Sql`]: ./enum.Error.html#variant.Sql
+    /// [`Error::Core`]: ./enum.Error.html#variant.Core
+    pub fn is_unique_violation(&self) -> bool {
+        match self {
+            Error::Core(e) => e.code() == mco_ret::MCO_S_DUPLICATE,
+
+            #[cfg(feature = "sql")]
+            Error::Sql(e) => e.code() == sql::mcosql_error_code::NOT_UNIQUE,
+
+            #[cfg(feature = "serde_json")]
+            Error::Json(_) => false,
+        }
+    }
+
+    /// Returns `true` if this error is transient, and the failed operation
+    /// (usually, the whole transaction) may succeed if retried.
+    ///
+    /// The following errors are considered retryable:
+    ///
+    /// - transaction conflicts: `MCO_E_CONFLICT`, `MCO_E_DISK_CONFLICT`,
+    ///   `MCO_E_BTREE_CONFLICT`, and the SQL engine's `SQL_CONFLICT`;
+    /// - busy resources: `MCO_S_BUSY`, `MCO_E_NW_BUSY`,
+    ///   `MCO_E_NW_WOULDBLOCK`, and `MCO_E_CLUSTER_BUSY`;
+    /// - lost connections: `MCO_S_DEAD_CONNECTION`, and the SQL engine's
+    ///   `COMMUNICATION_ERROR`;
+    /// - timeouts: `MCO_S_REST_TIMEOUT`, `MCO_E_NW_TIMEOUT`,
+    ///   `MCO_E_HA_TIMEOUT`, and `MCO_E_IOT_TIMEOUT`.
+    ///
+    /// All other errors, such as invalid parameters, missing objects,
+    /// constraint violations, and JSON decoding errors, are permanent.
+    /// Schema changes (see [`is_schema_changed()`]) are not considered
+    /// retryable either, since the query must be re-executed rather than
+    /// retried.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use extremedb::runtime::TxManager;
+    /// # use extremedb::sql::engine::Engine;
+    /// # use extremedb::sql::trans::{Mode, Transaction};
+    /// # use extremedb::{connection, database, runtime, sql};
+    /// # use extremedb::device::util;
+    /// # fn main() -> extremedb::Result<()> {
+    /// #     let runtime = runtime::Runtime::start(vec![]);
+    /// #     let mut db_params = database::Params::new();
+    /// #     db_params
+    /// #         .ddl_dict_size(32768)
+    /// #         .max_classes(100)
+    /// #         .max_indexes(1000);
+    /// #     let mut devs = util::DeviceContainer::new();
+    /// #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+    ///     let conn1 = connection::Connection::new(&db)?;
+    ///     let conn2 = connection::Connection::new(&db)?;
+    ///     let engine1 = sql::engine::LocalEngine::new(&conn1)?;
+    ///     let engine2 = sql::engine::LocalEngine::new(&conn2)?;
+    ///
+    ///     engine1.execute_statement("CREATE TABLE TestTable(i integer primary key, v integer);", &[])?;
+    ///     engine1.execute_statement("INSERT INTO TestTable(i, v) VALUES(1, 0);", &[])?;
+    ///
+    ///     // Permanent errors.
+    ///     let err = engine1
+    ///         .execute_statement("INSERT INTO TestTable(i, v) VALUES(1, 0);", &[])
+    ///         .unwrap_err();
+    ///     assert!(!err.is_retryable());
+    ///
+    ///     let err = engine1
+    ///         .execute_statement("INSERT INTO MissingTable(i) VALUES(1);", &[])
+    ///         .unwrap_err();
+    ///     assert!(!err.is_retryable());
+    ///
+    ///     // Concurrent updates of the same record conflict under MVCC.
+    ///     if runtime.info().transaction_manager() == Some(TxManager::Mvcc) {
+    ///         let update = "UPDATE TestTable SET v = v + 1 WHERE i = 1;";
+    ///
+    ///         let txn1 = Transaction::begin(&engine1, Mode::ReadWrite, 0)?;
+    ///         let txn2 = Transaction::begin(&engine2, Mode::ReadWrite, 0)?;
+    ///         txn1.execute_statement(update, &[])?;
+    ///
+    ///         let res = txn2.execute_statement(update, &[]);
+    ///         txn1.commit()?;
+    ///
+    ///         let err = res.and_then(|_| txn2.commit()).unwrap_err();
+    ///         assert!(err.is_retryable());
+    ///     }
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`is_schema_changed()`]: #method.is_schema_changed
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Error::Core(e) => matches!(
+                e.code(),
+                mco_ret::MCO_E_CONFLICT
+                    | mco_ret::MCO_E_DISK_CONFLICT
+                    | mco_ret::MCO_E_BTREE_CONFLICT
+                    | mco_ret::MCO_S_BUSY
+                    | mco_ret::MCO_E_NW_BUSY
+                    | mco_ret::MCO_E_NW_WOULDBLOCK
+                    | mco_ret::MCO_E_CLUSTER_BUSY
+                    | mco_ret::MCO_S_DEAD_CONNECTION
+                    | mco_ret::MCO_S_REST_TIMEOUT
+                    | mco_ret::MCO_E_NW_TIMEOUT
+                    | mco_ret::MCO_E_HA_TIMEOUT
+                    | mco_ret::MCO_E_IOT_TIMEOUT
+            ),
+
+            #[cfg(feature = "sql")]
+            Error::Sql(e) => matches!(
+                e.code(),
+                sql::mcosql_error_code::SQL_CONFLICT | sql::mcosql_error_code::COMMUNICATION_ERROR
+            ),
+
+            #[cfg(feature = "serde_json")]
+            Error::Json(_) => false,
+        }
+    }
 }
 
 impl error::Error for Error {}
@@ -229,6 +526,9 @@ impl Display for Error {
 
             #[cfg(feature = "sql")]
             Error::Sql(e) => e.fmt(f),
+
+            #[cfg(feature = "serde_json")]
+            Error::Json(e) => e.fmt(f),
         }
     }
 }
@@ -24,6 +24,14 @@
 //! See the [`options`] module for more information on runtime options.
 //!
 //! [`options`]: ./options/index.html
+//!
+//! # Out-of-Memory Conditions
+//!
+//! The runtime does not provide a hook which could be invoked to free some
+//! memory when a database allocation fails. Such failures are reported as
+//! `MCO_E_NOMEM` errors by the operation which caused them; the application
+//! can release the memory it controls (e.g. delete some objects in a new
+//! transaction) and retry the operation.
 
 use std::ffi::CStr;
 use std::mem::MaybeUninit;
@@ -107,6 +115,7 @@ pub mod options {
     /// Shared memory prefix (Windows only).
     ///
     /// The default value is `None`.
+    #[derive(Clone, Copy, Debug, PartialEq)]
     pub enum WinSharedMemoryPrefix {
         /// No prefix.
         None = mco_rt_defines::values::shm_windows::MCO_RT_WINDOWS_SHM_PREFIX_NONE as isize,
@@ -122,6 +131,7 @@ pub mod options {
     /// Shared memory security descriptor (Windows only).
     ///
     /// The default value is `Null`.
+    #[derive(Clone, Copy, Debug, PartialEq)]
     pub enum WinSharedMemoryDescr {
         /// Null descriptor; enables default system policy:
         /// the database will be accessible from the current session only.
@@ -138,6 +148,7 @@ pub mod options {
     ///
     /// By default, *e*X*treme*DB runtime uses the "anonymous" and "shared"
     /// flags.
+    #[derive(Clone, Copy, Debug, PartialEq)]
     pub struct PosixSharedMemoryOptions(u32);
 
     impl PosixSharedMemoryOptions {
@@ -167,6 +178,12 @@ pub mod options {
     }
 
     /// CRC32 algorithm used to verify persistent database consistency.
+    ///
+    /// The algorithm used by the runtime is reported by
+    /// [`Runtime::crc_algorithm()`].
+    ///
+    /// [`Runtime::crc_algorithm()`]: ../struct.Runtime.html#method.crc_algorithm
+    #[derive(Clone, Copy, Debug, PartialEq)]
     pub enum CRCAlgorithm {
         /// Do not compute CRC.
         None = mco_rt_defines::values::crc::MCO_RT_CRC32_NONE as isize,
@@ -181,9 +198,25 @@ pub mod options {
         CRC32C = mco_rt_defines::values::crc::MCO_RT_CRC32C as isize,
     }
 
+    impl CRCAlgorithm {
+        pub(crate) fn from_mco(v: u32) -> Option<Self> {
+            use mco_rt_defines::values::crc::*;
+
+            match v {
+                MCO_RT_CRC32_NONE => Some(CRCAlgorithm::None),
+                MCO_RT_CRC32 => Some(CRCAlgorithm::CRC32),
+                MCO_RT_CRC32_FAST => Some(CRCAlgorithm::CRC32Fast),
+                MCO_RT_CRC32_OLD => Some(CRCAlgorithm::CRC32Old),
+                MCO_RT_CRC32C => Some(CRCAlgorithm::CRC32C),
+                _ => None,
+            }
+        }
+    }
+
     /// Time resolution (precision) for `datetime` values.
     ///
     /// The default value is 1 (one second).
+    #[derive(Clone, Copy, Debug, PartialEq)]
     pub struct DateTimePrecision(u32);
 
     impl DateTimePrecision {
@@ -201,11 +234,59 @@ pub mod options {
         pub fn microseconds(val: u32) -> Self {
             Self(val.checked_mul(1_000_000).expect("value overflow"))
         }
+
+        /// Sets the `datetime` precision in nanoseconds.
+        ///
+        /// # Examples
+        ///
+        /// Timestamps with nanosecond resolution are stored without loss of
+        /// precision:
+        ///
+        /// ```
+        /// # use extremedb::runtime::options::{DateTimePrecision, Opt};
+        /// # use extremedb::sql::engine::Engine;
+        /// # use extremedb::{connection, database, device, runtime, sql};
+        /// # use extremedb::device::util;
+        /// # use std::time::{Duration, UNIX_EPOCH};
+        /// # fn main() -> extremedb::Result<()> {
+        ///     let runtime = runtime::Runtime::start(vec![Opt::DateTimePrecision(
+        ///         DateTimePrecision::nanoseconds(1),
+        ///     )]);
+        /// #     let mut db_params = database::Params::new();
+        /// #     db_params
+        /// #         .ddl_dict_size(32768)
+        /// #         .max_classes(100)
+        /// #         .max_indexes(1000);
+        /// #     let mut devs = util::DeviceContainer::new();
+        /// #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+        /// #     let conn = connection::Connection::new(&db)?;
+        /// #     let engine = sql::engine::LocalEngine::new(&conn)?;
+        ///     let ts = UNIX_EPOCH + Duration::new(1_600_000_000, 123_456_789);
+        ///
+        ///     engine.execute_statement("CREATE TABLE TestTable(ts timestamp);", &[])?;
+        ///     engine.execute_statement("INSERT INTO TestTable VALUES(?);", &[&ts])?;
+        ///
+        ///     let ds = engine.execute_query("SELECT ts FROM TestTable;", &[])?.unwrap();
+        ///     let mut cur = ds.cursor()?;
+        ///     assert!(cur.advance()?);
+        ///     let rec = cur.current_record().unwrap();
+        ///     assert_eq!(rec.get_at(0)?.to_system_time()?, ts);
+        /// #     Ok(())
+        /// # }
+        /// ```
+        pub fn nanoseconds(val: u32) -> Self {
+            Self(val.checked_mul(1_000_000_000).expect("value overflow"))
+        }
     }
 
     /// A runtime option.
     ///
-    /// The options can only be set when the runtime is started.
+    /// The options can only be set when the runtime is started. The options
+    /// applied to the running runtime are reported by
+    /// [`Runtime::applied_options()`].
+    ///
+    /// [`Runtime::applied_options()`]: ../struct.Runtime.html#method.applied_options
+    #[derive(Clone, Copy, Debug, PartialEq)]
     pub enum Opt {
         /// Shared memory options, Windows only.
         WinSharedMemory((WinSharedMemoryPrefix, WinSharedMemoryDescr)),
@@ -231,10 +312,10 @@ pub mod options {
     }
 
     impl Opt {
-        pub(super) fn raw(self) -> (i32, i32) {
+        pub(super) fn raw(&self) -> (i32, i32) {
             use mco_rt_defines::keys::*;
 
-            match self {
+            match *self {
                 Opt::WinSharedMemory((p, d)) => {
                     (MCO_RT_WINDOWS_SHM_OPT as i32, (p as i32 | d as i32))
                 }
@@ -322,6 +403,11 @@ impl Info {
         self.caps.mco_checklevel
     }
 
+    /// Returns `true` if this is an evaluation version of the runtime.
+    ///
+    /// Evaluation versions impose limits on the database usage. The runtime
+    /// does not report the limits themselves, nor the remaining allowance;
+    /// an operation which exceeds them fails with the `MCO_E_EVAL` error.
     pub fn evaluation_version(&self) -> bool {
         self.caps.evaluation_version != 0
     }
@@ -589,6 +675,35 @@ impl Info {
             Err(_) => "",
         }
     }
+
+    /// Returns the transaction manager linked into the application.
+    ///
+    /// The transaction manager library is selected at build time using the
+    /// `MCORS_CFG_TMGR` environment variable; it is not reported by the
+    /// runtime itself. Returns `None` if the variable was not set when
+    /// the crate was built.
+    pub fn transaction_manager(&self) -> Option<TxManager> {
+        match exdb_sys::MCORS_CFG_TMGR? {
+            "excl" => Some(TxManager::Exclusive),
+            "mursiw" => Some(TxManager::Mursiw),
+            "mvcc" => Some(TxManager::Mvcc),
+            _ => None,
+        }
+    }
+}
+
+/// Transaction manager.
+///
+/// Refer to the *e*X*treme*DB documentation for the description of the
+/// transaction managers.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TxManager {
+    /// Exclusive transaction manager (`excl`).
+    Exclusive,
+    /// Multiple-reader, single-writer transaction manager (`mursiw`).
+    Mursiw,
+    /// Multi-version concurrency control transaction manager (`mvcc`).
+    Mvcc,
 }
 
 /// Runtime interface.
@@ -618,7 +733,9 @@ impl Info {
 /// module page.
 ///
 /// [`options`]: ./options/index.html
-pub struct Runtime {}
+pub struct Runtime {
+    applied_opts: Vec<options::Opt>,
+}
 
 impl Runtime {
     /// Starts the *e*X*treme*DB runtime.
@@ -646,9 +763,9 @@ impl Runtime {
             }
         }
 
-        Runtime::apply_options(opts);
+        let applied_opts = Runtime::apply_options(opts);
 
-        Runtime {}
+        Runtime { applied_opts }
     }
 
     /// Returns the information about the active runtime.
@@ -656,13 +773,78 @@ impl Runtime {
         Runtime::info_impl()
     }
 
-    fn apply_options(opts: Vec<options::Opt>) {
-        for opt in opts {
+    /// Returns the options applied when the runtime was started, in the
+    /// order they were applied.
+    ///
+    /// This information can be included in diagnostics and bug reports.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use extremedb::runtime::{options::Opt, Runtime};
+    /// #
+    /// # fn main() {
+    ///     let runtime = Runtime::start(vec![
+    ///         Opt::MaxPatriciaDepth(100),
+    ///         Opt::ClassLoadMerge(true),
+    ///     ]);
+    ///
+    ///     for opt in runtime.applied_options() {
+    ///         println!("runtime option: {:?}", opt);
+    ///     }
+    ///
+    ///     assert_eq!(
+    ///         runtime.applied_options(),
+    ///         &[Opt::MaxPatriciaDepth(100), Opt::ClassLoadMerge(true)]
+    ///     );
+    /// # }
+    /// ```
+    pub fn applied_options(&self) -> &[options::Opt] {
+        &self.applied_opts
+    }
+
+    /// Returns the CRC algorithm used to verify the integrity of persistent
+    /// databases.
+    ///
+    /// The algorithm can be set using the [`Opt::CRCAlgorithm`] option when
+    /// the runtime is started. A persistent database must be opened with the
+    /// same algorithm it was written with; otherwise, opening it fails with
+    /// an error for which [`Error::is_crc_mismatch()`] returns `true`.
+    ///
+    /// Returns `None` if the runtime reports an unknown algorithm.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use extremedb::runtime::options::{CRCAlgorithm, Opt};
+    /// # use extremedb::runtime::Runtime;
+    /// #
+    /// # fn main() {
+    ///     let runtime = Runtime::start(vec![Opt::CRCAlgorithm(CRCAlgorithm::CRC32C)]);
+    ///     assert_eq!(runtime.crc_algorithm(), Some(CRCAlgorithm::CRC32C));
+    /// # }
+    /// ```
+    ///
+    /// [`Opt::CRCAlgorithm`]: ./options/enum.Opt.html#variant.CRCAlgorithm
+    /// [`Error::is_crc_mismatch()`]: ../enum.Error.html#method.is_crc_mismatch
+    pub fn crc_algorithm(&self) -> Option<options::CRCAlgorithm> {
+        let v = unsafe {
+            exdb_sys::mco_runtime_getoption(
+                options::mco_rt_defines::keys::MCO_RT_CRC_ALGORITHM as i32,
+            )
+        };
+        options::CRCAlgorithm::from_mco(v as u32)
+    }
+
+    fn apply_options(opts: Vec<options::Opt>) -> Vec<options::Opt> {
+        for opt in &opts {
             let (o, v) = opt.raw();
             unsafe {
                 exdb_sys::mco_runtime_setoption(o, v);
             }
         }
+
+        opts
     }
 
     pub(crate) fn info_impl() -> Info {
@@ -187,6 +187,21 @@
 //! #     Ok(())
 //! # }
 //! ```
+//!
+//! # SQL API Limitations
+//!
+//! This module is built on the *e*X*treme*DB SQL C API, which covers the
+//! execution of statements and the access to the resulting data sources,
+//! records, and values. The following are not exposed by that API, and
+//! hence are not available in this crate:
+//!
+//! - The dynamic dictionary, including the descriptions of the table
+//!   columns (e.g. their nullability) and indexes;
+//! - The query plans and cost estimates;
+//! - A statement preparation step separate from the execution;
+//! - Progress callbacks, and interruption of a running statement;
+//! - The internal object identifiers of the records;
+//! - The contents of the nested data source and list values.
 
 use crate::exdb_sys;
 
@@ -202,6 +217,9 @@ pub mod value;
 #[cfg(feature = "rsql")]
 pub mod rsql;
 
+#[cfg(feature = "tokio")]
+pub mod async_engine;
+
 mod stmt;
 
 /// SQL return codes (generated by bindgen from `mcosql_error_code` in
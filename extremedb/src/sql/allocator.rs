@@ -10,8 +10,12 @@
 //! performance. These allocators are used to produce SQL values, data sources,
 //! records, etc.
 //!
-//! It is currently impossible to create or reference an SQL allocator using
-//! this API. Public allocator API is considered for a future release.
+//! It is currently impossible to create an SQL allocator using this API.
+//! A reference to the allocator which owns a value can be obtained using
+//! the value reference's [`allocator()`] method. Public allocator API is
+//! considered for a future release.
+//!
+//! [`allocator()`]: ../value/struct.Ref.html#method.allocator
 
 use std::marker::PhantomData;
 use std::mem::MaybeUninit;
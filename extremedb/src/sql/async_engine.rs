@@ -0,0 +1,218 @@
+// async_engine.rs
+//
+// This file is a part of the eXtremeDB source code
+// Copyright (c) 2020 McObject LLC
+// All Rights Reserved
+
+//! An asynchronous SQL engine wrapper for Tokio.
+//!
+//! The SQL engine calls are blocking, and calling them directly from the
+//! asynchronous code stalls the executor. An [`AsyncEngine`] runs the
+//! statements and queries on Tokio's blocking thread pool, and returns
+//! futures which can be awaited.
+//!
+//! Each asynchronous engine owns a [`LocalEngineSession`], which is created
+//! and used by a single blocking task for the engine's entire lifetime: the
+//! session (and its database connection) is never shared with, or moved to,
+//! other threads. The operations submitted to the same asynchronous engine
+//! are executed sequentially; to run the operations concurrently, create an
+//! asynchronous engine for each task.
+//!
+//! Since the results cannot borrow the session, the queries return owned
+//! rows, produced using the [`FromRow`] trait.
+//!
+//! This module is only available when the `tokio` feature is enabled.
+//!
+//! # Examples
+//!
+//! ```
+//! # use extremedb::sql::async_engine::AsyncEngine;
+//! # use extremedb::sql::engine::{Engine, LocalEngineRef};
+//! # use extremedb::{connection, database, runtime, sql};
+//! # use extremedb::device::util;
+//! # fn main() -> extremedb::Result<()> {
+//! #     let runtime = runtime::Runtime::start(vec![]);
+//! #     let mut db_params = database::Params::new();
+//! #     db_params
+//! #         .ddl_dict_size(32768)
+//! #         .max_classes(100)
+//! #         .max_indexes(1000);
+//! #     let mut devs = util::DeviceContainer::new();
+//! #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+//! #     let conn = connection::Connection::new(&db)?;
+//! #     let engine = sql::engine::LocalEngine::new(&conn)?;
+//!     engine.execute_statement("CREATE TABLE TestTable(i integer, s string);", &[])?;
+//!
+//!     // The engine must outlive the asynchronous engine.
+//!     let engine_ref = unsafe { LocalEngineRef::new_unbounded(&engine) };
+//!
+//!     let rt = tokio::runtime::Builder::new_current_thread().build().unwrap();
+//!
+//!     let rows = rt.block_on(async move {
+//!         let async_engine = AsyncEngine::new(engine_ref).await?;
+//!
+//!         let n = async_engine
+//!             .execute_statement(
+//!                 "INSERT INTO TestTable(i, s) VALUES(?, ?);",
+//!                 vec![Box::new(1), Box::new("Hello")],
+//!             )
+//!             .await?;
+//!         assert_eq!(n, 1);
+//!
+//!         let rows = async_engine
+//!             .execute_query::<(i64, String)>("SELECT i, s FROM TestTable;", vec![])
+//!             .await?;
+//!
+//!         async_engine.close().await?;
+//!         Ok::<_, extremedb::Error>(rows)
+//!     })?;
+//!
+//!     assert_eq!(rows, vec![(1, "Hello".to_string())]);
+//! #     Ok(())
+//! # }
+//! ```
+//!
+//! [`AsyncEngine`]: ./struct.AsyncEngine.html
+//! [`LocalEngineSession`]: ../engine/struct.LocalEngineSession.html
+//! [`FromRow`]: ../data_source/trait.FromRow.html
+
+use tokio::sync::{mpsc, oneshot};
+use tokio::task::{self, JoinHandle};
+
+use crate::sql::data_source::FromRow;
+use crate::sql::engine::{Engine, LocalEngineRef, LocalEngineSession};
+use crate::sql::mcosql_error_code;
+use crate::sql::value::ToValue;
+use crate::{Error, Result};
+
+type Job = Box<dyn FnOnce(&LocalEngineSession<'static>) + Send>;
+
+/// An asynchronous SQL engine.
+///
+/// The statement and query parameters are passed as boxed values, since
+/// they have to be sent to the blocking task.
+///
+/// If the blocking task is not running anymore (for instance, because a
+/// submitted operation panicked), the operations fail with the
+/// `SQL_INVALID_STATE` error.
+pub struct AsyncEngine {
+    jobs: mpsc::UnboundedSender<Job>,
+    worker: JoinHandle<()>,
+}
+
+impl AsyncEngine {
+    /// Creates a new asynchronous engine.
+    ///
+    /// A blocking task is spawned, and a new session is created for the
+    /// engine referenced by `engine_ref`. Since the engine reference must
+    /// have the `'static` lifetime, it is usually created using the unsafe
+    /// [`LocalEngineRef::new_unbounded()`]; the calling code is responsible
+    /// for [closing] the asynchronous engine prior to dropping the engine.
+    ///
+    /// This function must be called in the context of a Tokio runtime.
+    ///
+    /// [`LocalEngineRef::new_unbounded()`]: ../engine/struct.LocalEngineRef.html#method.new_unbounded
+    /// [closing]: #method.close
+    pub async fn new(engine_ref: LocalEngineRef<'static>) -> Result<Self> {
+        let (jobs, mut jobs_rx) = mpsc::unbounded_channel::<Job>();
+        let (ready, ready_rx) = oneshot::channel();
+
+        let worker = task::spawn_blocking(move || {
+            let session = match LocalEngineSession::new(engine_ref) {
+                Ok(session) => {
+                    let _ = ready.send(Ok(()));
+                    session
+                }
+                Err(e) => {
+                    let _ = ready.send(Err(e));
+                    return;
+                }
+            };
+
+            while let Some(job) = jobs_rx.blocking_recv() {
+                job(&session);
+            }
+        });
+
+        ready_rx.await.map_err(|_| worker_stopped())??;
+
+        Ok(AsyncEngine { jobs, worker })
+    }
+
+    /// Runs `f` with the engine's session in the blocking task, and returns
+    /// its result.
+    ///
+    /// This method can be used to run multiple statements, or to process
+    /// the query results in place, without returning to the executor.
+    pub async fn run<T, F>(&self, f: F) -> Result<T>
+    where
+        F: FnOnce(&LocalEngineSession<'static>) -> Result<T> + Send + 'static,
+        T: Send + 'static,
+    {
+        let (tx, rx) = oneshot::channel();
+
+        self.jobs
+            .send(Box::new(move |session| {
+                let _ = tx.send(f(session));
+            }))
+            .map_err(|_| worker_stopped())?;
+
+        rx.await.map_err(|_| worker_stopped())?
+    }
+
+    /// Executes the SQL statement.
+    ///
+    /// Returns the number of affected rows, if available.
+    pub async fn execute_statement(
+        &self,
+        sql: &str,
+        args: Vec<Box<dyn ToValue + Send>>,
+    ) -> Result<i64> {
+        let sql = sql.to_string();
+
+        self.run(move |session| session.execute_statement(&sql, &arg_refs(&args)))
+            .await
+    }
+
+    /// Executes the SQL query, and converts the produced records to `T`.
+    ///
+    /// Returns an empty vector if the query does not produce a data source.
+    pub async fn execute_query<T>(
+        &self,
+        sql: &str,
+        args: Vec<Box<dyn ToValue + Send>>,
+    ) -> Result<Vec<T>>
+    where
+        T: FromRow + Send + 'static,
+    {
+        let sql = sql.to_string();
+
+        self.run(move |session| {
+            let ds = match session.execute_query(&sql, &arg_refs(&args))? {
+                Some(ds) => ds,
+                None => return Ok(Vec::new()),
+            };
+
+            let rows = ds.cursor()?.into_rows().collect::<Result<Vec<T>>>()?;
+            Ok(rows)
+        })
+        .await
+    }
+
+    /// Closes the asynchronous engine.
+    ///
+    /// The operations submitted earlier are completed, and the session is
+    /// released. This method waits for the blocking task to finish.
+    pub async fn close(self) -> Result<()> {
+        drop(self.jobs);
+        self.worker.await.map_err(|_| worker_stopped())
+    }
+}
+
+fn arg_refs(args: &[Box<dyn ToValue + Send>]) -> Vec<&dyn ToValue> {
+    args.iter().map(|arg| &**arg as &dyn ToValue).collect()
+}
+
+fn worker_stopped() -> Error {
+    Error::new_sql(mcosql_error_code::SQL_INVALID_STATE)
+}
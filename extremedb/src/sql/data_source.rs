@@ -19,6 +19,11 @@
 //! are invalidated when the cursor is advanced, and cannot outlive the cursor
 //! or the data source. This contradicts the standard `Iterator`'s semantics.
 //!
+//! When the records have to be processed using the iterator adapters, the
+//! cursor can be converted into an iterator over the owned row snapshots
+//! using [`Cursor::into_rows()`]. The column values are copied into native
+//! Rust types as the iterator is advanced.
+//!
 //! # Examples
 //!
 //! Execute a `SELECT` query to obtain a data source:
@@ -127,29 +132,52 @@
 //! #     Ok(())
 //! # }
 //! ```
+//!
+//! # Object Identifiers
+//!
+//! The records do not expose the internal object identifiers of the
+//! underlying database objects (see the [SQL API limitations]). Applications which need a stable row identity (for
+//! instance, to update or delete the rows later) should declare a key
+//! column in the table and select it explicitly, along with the other
+//! columns.
+//!
+//! [`Cursor::into_rows()`]: ./struct.Cursor.html#method.into_rows
+//! [SQL API limitations]: ../index.html#sql-api-limitations
 
+use std::collections::HashMap;
 use std::ffi::CStr;
 use std::marker::PhantomData;
 use std::mem::MaybeUninit;
 use std::ptr;
+use std::sync::atomic::{AtomicU64, Ordering};
 
-use crate::sql::value::{Ref, Type};
+use crate::sql::value::{FromValue, OwnedValue, Ref, Type};
 use crate::sql::{mcosql_error_code, result_from_code};
-use crate::{exdb_sys, Error, Result};
+use crate::{exdb_sys, mco_ret, Error, Result};
 
 /// A data source.
 ///
 /// A data source contains records (table rows) produced by a query.
+///
+/// The number of records in a data source is not known in advance: the SQL
+/// API used by this crate does not report it, even for the data sources
+/// which are fully materialized by the engine. The records have to be
+/// counted using a [`Cursor`], or a separate `SELECT COUNT(*)` query can be
+/// executed.
+///
+/// [`Cursor`]: ./struct.Cursor.html
 pub struct DataSource<'a> {
     owner: PhantomData<&'a ()>,
     h: exdb_sys::data_source_t,
+    schema_version: Option<&'a AtomicU64>,
 }
 
 impl<'a> DataSource<'a> {
-    pub(crate) fn new<T: ?Sized>(h: exdb_sys::data_source_t, _owner: &'a T) -> Self {
+    pub(crate) fn new(h: exdb_sys::data_source_t, schema_version: Option<&'a AtomicU64>) -> Self {
         DataSource {
             owner: PhantomData,
             h,
+            schema_version,
         }
     }
 
@@ -188,6 +216,57 @@ impl<'a> DataSource<'a> {
         Ok((ty, name.to_string()))
     }
 
+    /// Returns the metadata of all the columns in this data source, in the
+    /// column order.
+    ///
+    /// The nullability of the columns is not reported (see the
+    /// [SQL API limitations]).
+    ///
+    /// [SQL API limitations]: ../index.html#sql-api-limitations
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use extremedb::sql::engine::Engine;
+    /// # use extremedb::sql::value::Type;
+    /// # use extremedb::{connection, database, runtime, sql};
+    /// # use extremedb::device::util;
+    /// # fn main() -> extremedb::Result<()> {
+    /// #     let runtime = runtime::Runtime::start(vec![]);
+    /// #     let mut db_params = database::Params::new();
+    /// #     db_params
+    /// #         .ddl_dict_size(32768)
+    /// #         .max_classes(100)
+    /// #         .max_indexes(1000);
+    /// #     let mut devs = util::DeviceContainer::new();
+    /// #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+    /// #     let conn = connection::Connection::new(&db)?;
+    /// #     let engine = sql::engine::LocalEngine::new(&conn)?;
+    ///     engine.execute_statement("CREATE TABLE TestTable(i bigint, s string);", &[])?;
+    ///
+    ///     let ds = engine.execute_query("SELECT i, s FROM TestTable;", &[])?.unwrap();
+    ///     let columns = ds.columns()?;
+    ///
+    ///     assert_eq!(columns.len(), 2);
+    ///     assert_eq!(columns[0].name(), "i");
+    ///     assert_eq!(columns[0].value_type(), Type::Int8);
+    ///     assert_eq!(columns[1].name(), "s");
+    ///     assert_eq!(columns[1].value_type(), Type::String);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn columns(&self) -> Result<Vec<ColumnInfo>> {
+        let n = self.n_columns()?;
+        let mut ret = Vec::with_capacity(n);
+
+        for col in 0..n {
+            let (ty, name) = self.column_info(col)?;
+            ret.push(ColumnInfo { name, ty });
+        }
+
+        Ok(ret)
+    }
+
     /// Creates a cursor for this data source.
     pub fn cursor(&self) -> Result<Cursor> {
         let mut cur = MaybeUninit::uninit();
@@ -195,6 +274,83 @@ impl<'a> DataSource<'a> {
         result_from_code(unsafe { exdb_sys::mcosql_get_cursor(self.h, cur.as_mut_ptr()) })
             .and(Ok(Cursor::new(self, unsafe { cur.assume_init() })))
     }
+
+    /// Iterates over the records of this data source in chunks.
+    ///
+    /// The records are converted to [`OwnedRow`]s and accumulated until
+    /// `chunk_size` rows are collected; the chunk is then passed to `f`
+    /// and discarded. The last chunk can be shorter than `chunk_size`, but
+    /// is never empty. This allows for processing large result sets in
+    /// batches without holding them in memory entirely. Iteration stops at
+    /// the first error returned by `f`.
+    ///
+    /// A new cursor is created for the iteration. Returns
+    /// `MCO_E_ILLEGAL_PARAM` if `chunk_size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use extremedb::sql::engine::Engine;
+    /// # use extremedb::{connection, database, runtime, sql};
+    /// # use extremedb::device::util;
+    /// # fn main() -> extremedb::Result<()> {
+    /// #     let runtime = runtime::Runtime::start(vec![]);
+    /// #     let mut db_params = database::Params::new();
+    /// #     db_params
+    /// #         .ddl_dict_size(32768)
+    /// #         .max_classes(100)
+    /// #         .max_indexes(1000);
+    /// #     let mut devs = util::DeviceContainer::new();
+    /// #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+    /// #     let conn = connection::Connection::new(&db)?;
+    /// #     let engine = sql::engine::LocalEngine::new(&conn)?;
+    ///     engine.execute_statement("CREATE TABLE TestTable(i integer);", &[])?;
+    ///     for i in 0..2500 {
+    ///         engine.execute_statement("INSERT INTO TestTable(i) VALUES(?);", &[&i])?;
+    ///     }
+    ///
+    ///     let ds = engine.execute_query("SELECT i FROM TestTable;", &[])?.unwrap();
+    ///
+    ///     let mut sizes = Vec::new();
+    ///     ds.for_each_chunk(1000, |rows| {
+    ///         sizes.push(rows.len());
+    ///         Ok(())
+    ///     })?;
+    ///
+    ///     assert_eq!(sizes, vec![1000, 1000, 500]);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`OwnedRow`]: ./type.OwnedRow.html
+    pub fn for_each_chunk<F>(&self, chunk_size: usize, mut f: F) -> Result<()>
+    where
+        F: FnMut(&[OwnedRow]) -> Result<()>,
+    {
+        if chunk_size == 0 {
+            return Err(Error::new_core(mco_ret::MCO_E_ILLEGAL_PARAM));
+        }
+
+        let mut cur = self.cursor()?;
+        let mut chunk = Vec::with_capacity(chunk_size);
+
+        while cur.advance()? {
+            if let Some(rec) = cur.current_record() {
+                chunk.push(rec.to_owned_row()?);
+            }
+
+            if chunk.len() == chunk_size {
+                f(&chunk)?;
+                chunk.clear();
+            }
+        }
+
+        if !chunk.is_empty() {
+            f(&chunk)?;
+        }
+
+        Ok(())
+    }
 }
 
 impl<'a> Drop for DataSource<'a> {
@@ -204,30 +360,105 @@ impl<'a> Drop for DataSource<'a> {
     }
 }
 
+/// Column metadata of a data source.
+///
+/// Column information is returned by [`DataSource::columns()`].
+///
+/// [`DataSource::columns()`]: ./struct.DataSource.html#method.columns
+#[derive(Clone, Debug, PartialEq)]
+pub struct ColumnInfo {
+    name: String,
+    ty: Type,
+}
+
+impl ColumnInfo {
+    /// Returns the name of the column.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the SQL type of the column.
+    pub fn value_type(&self) -> Type {
+        self.ty
+    }
+}
+
+/// A record detached from its data source.
+///
+/// Owned rows are produced by [`Record::to_owned_row()`], and contain one
+/// [`OwnedValue`] per column.
+///
+/// [`Record::to_owned_row()`]: ./struct.Record.html#method.to_owned_row
+/// [`OwnedValue`]: ../value/enum.OwnedValue.html
+pub type OwnedRow = Vec<OwnedValue>;
+
 /// A cursor.
 ///
 /// A cursor is used to iterate over the records in a data source. It is
 /// initially positioned before the first item.
 pub struct Cursor<'a> {
-    source: PhantomData<&'a DataSource<'a>>,
+    source: &'a DataSource<'a>,
     h: exdb_sys::cursor_t,
     rec_h: exdb_sys::record_t,
+    schema_version: Option<u64>,
+    stale: bool,
 }
 
 impl<'a> Cursor<'a> {
-    pub(crate) fn new(_source: &'a DataSource, h: exdb_sys::cursor_t) -> Self {
+    pub(crate) fn new(source: &'a DataSource, h: exdb_sys::cursor_t) -> Self {
         Cursor {
-            source: PhantomData,
+            source,
             h,
             rec_h: ptr::null_mut(),
+            schema_version: source
+                .schema_version
+                .map(|version| version.load(Ordering::Acquire)),
+            stale: false,
+        }
+    }
+
+    // Returns true if DDL statements were executed in the database since
+    // the cursor was created. Used only to classify the cursor failures.
+    fn schema_changed(&self) -> bool {
+        match (self.source.schema_version, self.schema_version) {
+            (Some(current), Some(opened)) => current.load(Ordering::Acquire) != opened,
+            _ => false,
         }
     }
 
+    fn invalidate(&mut self) -> Error {
+        self.rec_h = ptr::null_mut();
+        self.stale = true;
+        Error::new_core(mco_ret::MCO_E_SCHEMA_CHANGED)
+    }
+
     /// Advances the cursor.
     ///
     /// If this function returns `true`, the current record can be accessed.
     /// `false` indicates that the cursor has been moved past the last record.
+    ///
+    /// If the engine fails to move the cursor after the database schema was
+    /// changed by a DDL statement (executed using any local engine of the
+    /// same database), this function fails with the `MCO_E_SCHEMA_CHANGED`
+    /// error, for which [`Error::is_schema_changed()`] returns `true`, and
+    /// the cursor becomes [stale]. The query has to be re-executed in this
+    /// case. Schema changes which do not affect the cursor, such as creating
+    /// an unrelated table, do not interrupt the iteration.
+    ///
+    /// The SQL API reports the cursor errors using the SQL status codes
+    /// only, so a failure is attributed to a schema change if the database
+    /// [schema version] differs from the one at the time the cursor was
+    /// created. Other failures are returned as is. The schema changes are
+    /// not detected for the data sources produced by the remote engines.
+    ///
+    /// [`Error::is_schema_changed()`]: ../../enum.Error.html#method.is_schema_changed
+    /// [stale]: #method.is_stale
+    /// [schema version]: ../../database/struct.Database.html#method.schema_version
     pub fn advance(&mut self) -> Result<bool> {
+        if self.stale {
+            return Err(self.invalidate());
+        }
+
         let rc = unsafe { exdb_sys::mcosql_cursor_move_next(self.h, &mut self.rec_h) };
 
         match rc {
@@ -236,6 +467,9 @@ impl<'a> Cursor<'a> {
                 self.rec_h = ptr::null_mut();
                 Ok(false)
             }
+            // The schema was changed since the cursor was created, and the
+            // engine cannot move it anymore.
+            _ if self.schema_changed() => Err(self.invalidate()),
             _ => {
                 self.rec_h = ptr::null_mut();
                 Err(Error::new_sql(rc))
@@ -243,6 +477,62 @@ impl<'a> Cursor<'a> {
         }
     }
 
+    /// Returns `true` if the cursor was invalidated by a database schema
+    /// change.
+    ///
+    /// A stale cursor cannot be advanced anymore; the query must be executed
+    /// again to obtain a new data source.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use extremedb::sql::engine::Engine;
+    /// # use extremedb::{connection, database, device, runtime, sql};
+    /// # use extremedb::device::util;
+    /// # fn main() -> extremedb::Result<()> {
+    /// #     let runtime = runtime::Runtime::start(vec![]);
+    /// #     let mut db_params = database::Params::new();
+    /// #     db_params
+    /// #         .ddl_dict_size(32768)
+    /// #         .max_classes(100)
+    /// #         .max_indexes(1000);
+    /// #     let mut devs = util::DeviceContainer::new();
+    /// #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+    /// #     let conn = connection::Connection::new(&db)?;
+    /// #     let engine = sql::engine::LocalEngine::new(&conn)?;
+    ///     engine.execute_statement("CREATE TABLE TestTable(i integer);", &[])?;
+    ///     for i in 0..100 {
+    ///         engine.execute_statement("INSERT INTO TestTable(i) VALUES(?);", &[&i])?;
+    ///     }
+    ///
+    ///     let ds = engine.execute_query("SELECT i FROM TestTable;", &[])?.unwrap();
+    ///     let mut cur = ds.cursor()?;
+    ///     assert!(cur.advance()?);
+    ///     assert!(!cur.is_stale());
+    ///
+    ///     // Creating an unrelated table does not affect the cursor.
+    ///     let conn2 = connection::Connection::new(&db)?;
+    ///     let engine2 = sql::engine::LocalEngine::new(&conn2)?;
+    ///     engine2.execute_statement("CREATE TABLE OtherTable(s string);", &[])?;
+    ///     assert!(cur.advance()?);
+    ///     assert!(!cur.is_stale());
+    ///
+    ///     // Dropping the queried table does.
+    ///     engine2.execute_statement("DROP TABLE TestTable;", &[])?;
+    ///
+    ///     let e = cur.advance().unwrap_err();
+    ///     assert!(e.is_schema_changed());
+    ///     assert!(cur.is_stale());
+    ///
+    ///     // The stale cursor keeps failing.
+    ///     assert!(cur.advance().unwrap_err().is_schema_changed());
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn is_stale(&self) -> bool {
+        self.stale
+    }
+
     /// Returns the record currently pointed at by the cursor.
     ///
     /// Returns `None` if the cursor hasn't been advanced at least once, or has
@@ -254,22 +544,116 @@ impl<'a> Cursor<'a> {
             Some(Record::new(self, self.rec_h))
         }
     }
+
+    /// Converts the cursor into an iterator over the owned rows.
+    ///
+    /// Each record is converted to `T` using the [`FromRow`] trait as the
+    /// iterator is advanced, so the produced items do not depend on the
+    /// cursor's lifetime. The iterator yields `Err` if the cursor fails to
+    /// advance or a record cannot be converted; it is exhausted after a
+    /// cursor error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use extremedb::sql::engine::Engine;
+    /// # use extremedb::{connection, database, device, runtime, sql};
+    /// # use extremedb::device::util;
+    /// # fn main() -> extremedb::Result<()> {
+    /// #     let runtime = runtime::Runtime::start(vec![]);
+    /// #     let mut db_params = database::Params::new();
+    /// #     db_params
+    /// #         .ddl_dict_size(32768)
+    /// #         .max_classes(100)
+    /// #         .max_indexes(1000);
+    /// #     let mut devs = util::DeviceContainer::new();
+    /// #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+    /// #     let conn = connection::Connection::new(&db)?;
+    /// #     let engine = sql::engine::LocalEngine::new(&conn)?;
+    ///     engine.execute_statement("CREATE TABLE TestTable(i integer, s string);", &[])?;
+    ///     engine.execute_statement("INSERT INTO TestTable(i, s) VALUES(?, ?);", &[&1, &"Hello"])?;
+    ///     engine.execute_statement("INSERT INTO TestTable(i, s) VALUES(?, ?);", &[&2, &"World"])?;
+    ///
+    ///     let ds = engine.execute_query("SELECT i, s FROM TestTable ORDER BY i;", &[])?.unwrap();
+    ///     let rows = ds
+    ///         .cursor()?
+    ///         .into_rows::<(i64, String)>()
+    ///         .collect::<extremedb::Result<Vec<_>>>()?;
+    ///
+    ///     assert_eq!(
+    ///         rows,
+    ///         vec![(1, "Hello".to_string()), (2, "World".to_string())]
+    ///     );
+    ///
+    ///     let ds = engine.execute_query("SELECT i FROM TestTable ORDER BY i;", &[])?.unwrap();
+    ///     let total: i64 = ds
+    ///         .cursor()?
+    ///         .into_rows::<(i64,)>()
+    ///         .map(|row| row.map(|(i,)| i))
+    ///         .sum::<extremedb::Result<i64>>()?;
+    ///     assert_eq!(total, 3);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`FromRow`]: ./trait.FromRow.html
+    pub fn into_rows<T: FromRow>(self) -> Rows<'a, T> {
+        Rows {
+            cursor: self,
+            done: false,
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// An iterator over the owned rows of a data source.
+///
+/// This iterator is created by [`Cursor::into_rows()`].
+///
+/// [`Cursor::into_rows()`]: ./struct.Cursor.html#method.into_rows
+pub struct Rows<'a, T> {
+    cursor: Cursor<'a>,
+    done: bool,
+    _marker: PhantomData<T>,
+}
+
+impl<'a, T: FromRow> Iterator for Rows<'a, T> {
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        match self.cursor.advance() {
+            Ok(true) => {
+                // The record is available after a successful advance().
+                let rec = self.cursor.current_record()?;
+                Some(T::from_row(&rec))
+            }
+            Ok(false) => {
+                self.done = true;
+                None
+            }
+            Err(e) => {
+                self.done = true;
+                Some(Err(e))
+            }
+        }
+    }
 }
 
 /// A record.
 ///
 /// Records are the actual rows of data produced by a `SELECT` SQL query.
 pub struct Record<'a> {
-    cursor: PhantomData<&'a Cursor<'a>>,
+    cursor: &'a Cursor<'a>,
     h: exdb_sys::record_t,
 }
 
 impl<'a> Record<'a> {
-    pub(crate) fn new(_cursor: &'a Cursor, h: exdb_sys::record_t) -> Self {
-        Record {
-            cursor: PhantomData,
-            h,
-        }
+    pub(crate) fn new(cursor: &'a Cursor, h: exdb_sys::record_t) -> Self {
+        Record { cursor, h }
     }
 
     /// Returns a reference to the value in the column `col`.
@@ -285,4 +669,406 @@ impl<'a> Record<'a> {
         })
         .and(Ok(Ref::from_handle(unsafe { ret.assume_init() }, self)))
     }
+
+    /// Returns a reference to the value in the column `name`.
+    ///
+    /// The column is looked up in the data source's column metadata on
+    /// every call; if the data source contains multiple columns with the
+    /// same name, the first one is returned. Returns `MCO_S_NOTFOUND` if
+    /// the column does not exist. When accessing the columns of many
+    /// records, use [`get_with()`] to resolve the column names once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use extremedb::sql::engine::Engine;
+    /// # use extremedb::{connection, database, device, runtime, sql};
+    /// # use extremedb::device::util;
+    /// # fn main() -> extremedb::Result<()> {
+    /// #     let runtime = runtime::Runtime::start(vec![]);
+    /// #     let mut db_params = database::Params::new();
+    /// #     db_params
+    /// #         .ddl_dict_size(32768)
+    /// #         .max_classes(100)
+    /// #         .max_indexes(1000);
+    /// #     let mut devs = util::DeviceContainer::new();
+    /// #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+    /// #     let conn = connection::Connection::new(&db)?;
+    /// #     let engine = sql::engine::LocalEngine::new(&conn)?;
+    ///     engine.execute_statement("CREATE TABLE TestTable(i integer, s string);", &[])?;
+    ///     engine.execute_statement("INSERT INTO TestTable(i, s) VALUES(?, ?);", &[&1, &"Hello"])?;
+    ///
+    ///     let ds = engine.execute_query("SELECT i, s FROM TestTable;", &[])?.unwrap();
+    ///     let mut cur = ds.cursor()?;
+    ///     assert!(cur.advance()?);
+    ///
+    ///     let rec = cur.current_record().unwrap();
+    ///     assert_eq!(rec.get_by_name("s")?.to_string()?, "Hello");
+    ///     assert_eq!(rec.get_by_name("i")?.to_i64()?, 1);
+    ///
+    ///     match rec.get_by_name("x") {
+    ///         Err(extremedb::Error::Core(e)) => assert_eq!(e.code(), extremedb::mco_ret::MCO_S_NOTFOUND),
+    ///         _ => panic!("unexpected result"),
+    ///     }
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`get_with()`]: #method.get_with
+    pub fn get_by_name(&self, name: &str) -> Result<Ref<'_>> {
+        let source = self.cursor.source;
+
+        for col in 0..source.n_columns()? {
+            let (_, col_name) = source.column_info(col)?;
+            if col_name == name {
+                return self.get_at(col);
+            }
+        }
+
+        Err(Error::new_core(mco_ret::MCO_S_NOTFOUND))
+    }
+
+    /// Returns a reference to the value in the column `name`, using the
+    /// column indexes resolved by the `schema`.
+    ///
+    /// The `schema` must be created for the data source this record
+    /// belongs to. Returns `MCO_S_NOTFOUND` if the column does not exist.
+    pub fn get_with(&self, schema: &RowSchema, name: &str) -> Result<Ref<'_>> {
+        let col = schema
+            .index_of(name)
+            .ok_or(Error::new_core(mco_ret::MCO_S_NOTFOUND))?;
+        self.get_at(col)
+    }
+
+    /// Returns an iterator over the columns of this record.
+    ///
+    /// For every column, the iterator yields its name, its type as reported
+    /// by the data source, and a reference to its value in this record.
+    /// This can be used to process the records of a query whose result
+    /// columns are not known in advance. The value references borrow the
+    /// record, and cannot outlive it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use extremedb::sql::engine::Engine;
+    /// # use extremedb::sql::value::Type;
+    /// # use extremedb::{connection, database, device, runtime, sql};
+    /// # use extremedb::device::util;
+    /// # fn main() -> extremedb::Result<()> {
+    /// #     let runtime = runtime::Runtime::start(vec![]);
+    /// #     let mut db_params = database::Params::new();
+    /// #     db_params
+    /// #         .ddl_dict_size(32768)
+    /// #         .max_classes(100)
+    /// #         .max_indexes(1000);
+    /// #     let mut devs = util::DeviceContainer::new();
+    /// #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+    /// #     let conn = connection::Connection::new(&db)?;
+    /// #     let engine = sql::engine::LocalEngine::new(&conn)?;
+    ///     engine.execute_statement("CREATE TABLE TestTable(i bigint, d double, s varchar);", &[])?;
+    ///     engine.execute_statement(
+    ///         "INSERT INTO TestTable(i, d, s) VALUES(?, ?, ?);",
+    ///         &[&1, &1.5, &"Hello"],
+    ///     )?;
+    ///
+    ///     let ds = engine.execute_query("SELECT i, d, s FROM TestTable;", &[])?.unwrap();
+    ///     let mut cur = ds.cursor()?;
+    ///     assert!(cur.advance()?);
+    ///
+    ///     let rec = cur.current_record().unwrap();
+    ///     let mut cols = rec.iter_columns();
+    ///
+    ///     let (name, ty, val) = cols.next().unwrap()?;
+    ///     assert_eq!((name.as_str(), ty), ("i", Type::Int8));
+    ///     assert_eq!(val.to_i64()?, 1);
+    ///
+    ///     let (name, ty, val) = cols.next().unwrap()?;
+    ///     assert_eq!((name.as_str(), ty), ("d", Type::Real8));
+    ///     assert_eq!(val.to_real()?, 1.5);
+    ///
+    ///     let (name, ty, val) = cols.next().unwrap()?;
+    ///     assert_eq!((name.as_str(), ty), ("s", Type::String));
+    ///     assert_eq!(val.as_str()?, "Hello");
+    ///
+    ///     assert!(cols.next().is_none());
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn iter_columns(&self) -> impl Iterator<Item = Result<(String, Type, Ref<'_>)>> + '_ {
+        let source = self.cursor.source;
+        let (n, err) = match source.n_columns() {
+            Ok(n) => (n, None),
+            Err(e) => (0, Some(e)),
+        };
+
+        err.map(Err).into_iter().chain((0..n).map(move |col| {
+            let (ty, name) = source.column_info(col)?;
+            Ok((name, ty, self.get_at(col)?))
+        }))
+    }
+
+    /// Returns the values of all columns of this record.
+    ///
+    /// The values are detached from the SQL engine's allocator, and can
+    /// be used after the cursor is advanced.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use extremedb::sql::engine::Engine;
+    /// # use extremedb::sql::value::OwnedValue;
+    /// # use extremedb::{connection, database, device, runtime, sql};
+    /// # use extremedb::device::util;
+    /// # fn main() -> extremedb::Result<()> {
+    /// #     let runtime = runtime::Runtime::start(vec![]);
+    /// #     let mut db_params = database::Params::new();
+    /// #     db_params
+    /// #         .ddl_dict_size(32768)
+    /// #         .max_classes(100)
+    /// #         .max_indexes(1000);
+    /// #     let mut devs = util::DeviceContainer::new();
+    /// #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+    /// #     let conn = connection::Connection::new(&db)?;
+    /// #     let engine = sql::engine::LocalEngine::new(&conn)?;
+    ///     engine.execute_statement("CREATE TABLE TestTable(i integer, d double, s string);", &[])?;
+    ///     engine.execute_statement(
+    ///         "INSERT INTO TestTable(i, d, s) VALUES(?, ?, ?);",
+    ///         &[&1, &1.5, &"Hello"],
+    ///     )?;
+    ///     engine.execute_statement(
+    ///         "INSERT INTO TestTable(i, d, s) VALUES(?, ?, ?);",
+    ///         &[&2, &2.5, &None::<&str>],
+    ///     )?;
+    ///
+    ///     let ds = engine
+    ///         .execute_query("SELECT i, d, s FROM TestTable ORDER BY i;", &[])?
+    ///         .unwrap();
+    ///     let mut cur = ds.cursor()?;
+    ///
+    ///     assert!(cur.advance()?);
+    ///     let first = cur.current_record().unwrap().to_owned_row()?;
+    ///
+    ///     assert!(cur.advance()?);
+    ///     let second = cur.current_record().unwrap().to_owned_row()?;
+    ///     assert!(!cur.advance()?);
+    ///
+    ///     assert_eq!(first.len(), 3);
+    ///     assert!(matches!(first[0], OwnedValue::Int(1)));
+    ///     assert!(matches!(first[1], OwnedValue::Real(d) if d == 1.5));
+    ///     assert!(matches!(&first[2], OwnedValue::String(s) if s == "Hello"));
+    ///
+    ///     assert!(matches!(second[0], OwnedValue::Int(2)));
+    ///     assert!(matches!(second[2], OwnedValue::Null));
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn to_owned_row(&self) -> Result<OwnedRow> {
+        let n = self.cursor.source.n_columns()?;
+        let mut row = Vec::with_capacity(n);
+
+        for col in 0..n {
+            row.push(OwnedValue::from_value(&*self.get_at(col)?)?);
+        }
+
+        Ok(row)
+    }
+}
+
+/// A trait for converting a [`Record`] to an owned Rust value.
+///
+/// This trait is implemented for [`OwnedRow`], which copies all the
+/// columns, and for tuples of up to eight [`FromValue`] types. A tuple
+/// conversion requires the record to have exactly as many columns as the
+/// tuple has elements, and fails with `INVALID_TYPE_CAST` otherwise.
+///
+/// [`Record`]: ./struct.Record.html
+/// [`OwnedRow`]: ./type.OwnedRow.html
+/// [`FromValue`]: ../value/trait.FromValue.html
+pub trait FromRow: Sized {
+    /// Converts the record to `Self`.
+    fn from_row(rec: &Record) -> Result<Self>;
+}
+
+/// Derive macro for the [`FromRow`] trait.
+///
+/// The trait can be derived for structures with named fields. Each field is
+/// read from the record column with the same name using
+/// [`Record::get_by_name()`], and converted using the field type's
+/// [`FromValue`] implementation. The column name can be overridden using
+/// the `#[extremedb(rename = "name")]` field attribute. Missing columns are
+/// reported as `MCO_S_NOTFOUND` errors, and failed conversions as the
+/// corresponding conversion errors. The record columns which do not
+/// correspond to any field are ignored.
+///
+/// This macro is only available when the `derive` feature is enabled.
+///
+/// # Examples
+///
+/// ```
+/// # use extremedb::sql::data_source::FromRow;
+/// # use extremedb::sql::engine::Engine;
+/// # use extremedb::{connection, database, runtime, sql};
+/// # use extremedb::device::util;
+/// #[derive(Debug, PartialEq, FromRow)]
+/// struct MyRow {
+///     id: i64,
+///     #[extremedb(rename = "s")]
+///     name: Option<String>,
+/// }
+///
+/// # fn main() -> extremedb::Result<()> {
+/// #     let runtime = runtime::Runtime::start(vec![]);
+/// #     let mut db_params = database::Params::new();
+/// #     db_params
+/// #         .ddl_dict_size(32768)
+/// #         .max_classes(100)
+/// #         .max_indexes(1000);
+/// #     let mut devs = util::DeviceContainer::new();
+/// #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+/// #     let conn = connection::Connection::new(&db)?;
+/// #     let engine = sql::engine::LocalEngine::new(&conn)?;
+///     engine.execute_statement("CREATE TABLE TestTable(id integer, s string);", &[])?;
+///     engine.execute_statement("INSERT INTO TestTable(id, s) VALUES(?, ?);", &[&1, &"Hello"])?;
+///     engine.execute_statement("INSERT INTO TestTable(id, s) VALUES(?, ?);", &[&2, &None::<&str>])?;
+///
+///     let ds = engine.execute_query("SELECT s, id FROM TestTable ORDER BY id;", &[])?.unwrap();
+///     let rows = ds
+///         .cursor()?
+///         .into_rows::<MyRow>()
+///         .collect::<extremedb::Result<Vec<_>>>()?;
+///
+///     assert_eq!(
+///         rows,
+///         vec![
+///             MyRow { id: 1, name: Some("Hello".to_string()) },
+///             MyRow { id: 2, name: None },
+///         ]
+///     );
+///
+///     // The `s` column is missing.
+///     let ds = engine.execute_query("SELECT id, s AS t FROM TestTable;", &[])?.unwrap();
+///     assert!(ds.cursor()?.into_rows::<MyRow>().all(|row| row.is_err()));
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [`FromRow`]: ./trait.FromRow.html
+/// [`Record::get_by_name()`]: ./struct.Record.html#method.get_by_name
+/// [`FromValue`]: ../value/trait.FromValue.html
+#[cfg(feature = "derive")]
+pub use extremedb_derive::FromRow;
+
+impl FromRow for OwnedRow {
+    fn from_row(rec: &Record) -> Result<Self> {
+        rec.to_owned_row()
+    }
+}
+
+macro_rules! impl_from_row_tuple {
+    ($n:expr; $($ty:ident $col:tt),+) => {
+        impl<$($ty: FromValue),+> FromRow for ($($ty,)+) {
+            fn from_row(rec: &Record) -> Result<Self> {
+                if rec.cursor.source.n_columns()? != $n {
+                    return Err(Error::new_sql(mcosql_error_code::INVALID_TYPE_CAST));
+                }
+
+                Ok(($($ty::from_value(&*rec.get_at($col)?)?,)+))
+            }
+        }
+    };
+}
+
+impl_from_row_tuple!(1; A 0);
+impl_from_row_tuple!(2; A 0, B 1);
+impl_from_row_tuple!(3; A 0, B 1, C 2);
+impl_from_row_tuple!(4; A 0, B 1, C 2, D 3);
+impl_from_row_tuple!(5; A 0, B 1, C 2, D 3, E 4);
+impl_from_row_tuple!(6; A 0, B 1, C 2, D 3, E 4, F 5);
+impl_from_row_tuple!(7; A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+impl_from_row_tuple!(8; A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+
+/// Column names of a data source.
+///
+/// A row schema maps the column names of a data source to the column
+/// indexes. It is created once for a data source and used to access the
+/// columns of its records by name using [`Record::get_with()`], avoiding
+/// the column metadata lookups for every record.
+///
+/// # Examples
+///
+/// ```
+/// # use extremedb::sql::data_source::RowSchema;
+/// # use extremedb::sql::engine::Engine;
+/// # use extremedb::{connection, database, device, runtime, sql};
+/// # use extremedb::device::util;
+/// # fn main() -> extremedb::Result<()> {
+/// #     let runtime = runtime::Runtime::start(vec![]);
+/// #     let mut db_params = database::Params::new();
+/// #     db_params
+/// #         .ddl_dict_size(32768)
+/// #         .max_classes(100)
+/// #         .max_indexes(1000);
+/// #     let mut devs = util::DeviceContainer::new();
+/// #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+/// #     let conn = connection::Connection::new(&db)?;
+/// #     let engine = sql::engine::LocalEngine::new(&conn)?;
+///     engine.execute_statement("CREATE TABLE TestTable(i integer, s string);", &[])?;
+///     for i in 0..1000 {
+///         engine.execute_statement("INSERT INTO TestTable(i, s) VALUES(?, ?);", &[&i, &"Hello"])?;
+///     }
+///
+///     let ds = engine.execute_query("SELECT i, s FROM TestTable ORDER BY i;", &[])?.unwrap();
+///     let schema = RowSchema::new(&ds)?;
+///     assert_eq!(schema.index_of("s"), Some(1));
+///
+///     let mut cur = ds.cursor()?;
+///     let mut n = 0;
+///     while cur.advance()? {
+///         let rec = cur.current_record().unwrap();
+///
+///         // Cached column indexes.
+///         let i = rec.get_with(&schema, "i")?.to_i64()?;
+///         let s = rec.get_with(&schema, "s")?.to_string()?;
+///
+///         // Column indexes resolved for every record.
+///         let uncached = RowSchema::new(&ds)?;
+///         assert_eq!(rec.get_with(&uncached, "i")?.to_i64()?, i);
+///         assert_eq!(rec.get_with(&uncached, "s")?.to_string()?, s);
+///
+///         assert_eq!(i, n);
+///         n += 1;
+///     }
+///     assert_eq!(n, 1000);
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [`Record::get_with()`]: ./struct.Record.html#method.get_with
+pub struct RowSchema {
+    columns: HashMap<String, usize>,
+}
+
+impl RowSchema {
+    /// Creates a new row schema for the data source.
+    pub fn new(ds: &DataSource) -> Result<Self> {
+        let n = ds.n_columns()?;
+        let mut columns = HashMap::with_capacity(n);
+
+        for col in 0..n {
+            let (_, name) = ds.column_info(col)?;
+            columns.entry(name).or_insert(col);
+        }
+
+        Ok(RowSchema { columns })
+    }
+
+    /// Returns the index of the column `name`, or `None` if the column
+    /// does not exist.
+    ///
+    /// If the data source contains multiple columns with the same name,
+    /// the index of the first one is returned.
+    pub fn index_of(&self, name: &str) -> Option<usize> {
+        self.columns.get(name).copied()
+    }
 }
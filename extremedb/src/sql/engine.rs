@@ -138,16 +138,110 @@
 //! #     Ok(())
 //! # }
 //! ```
+//!
+//! # Schema Export
+//!
+//! The engines do not provide a way to export the database schema as SQL DDL
+//! text, since the dynamic dictionary is not accessible (see the
+//! [SQL API limitations]). Applications which need to recreate the schema (e.g. for
+//! migration purposes) should keep the DDL statements used to create it.
+//!
+//! # Progress and Cancellation
+//!
+//! The SQL engines do not report progress while a statement is being
+//! executed, and a running statement cannot be interrupted (see the
+//! [SQL API limitations]). This includes bulk statements
+//! such as `INSERT ... SELECT`: the number of affected rows is only reported
+//! by [`Engine::execute_statement()`] when the statement completes.
+//!
+//! Applications which need progress feedback or cancellation for large
+//! operations can split them into batches (e.g. by key ranges) executed in
+//! a single [`Transaction`]. Progress can be reported and cancellation
+//! checked at the statement boundaries; rolling the transaction back
+//! discards the batches executed so far:
+//!
+//! ```
+//! # use extremedb::sql::engine::Engine;
+//! # use extremedb::sql::trans::{Mode, Transaction};
+//! # use extremedb::{connection, database, runtime, sql};
+//! # use extremedb::device::util;
+//! # fn main() -> extremedb::Result<()> {
+//! #     let runtime = runtime::Runtime::start(vec![]);
+//! #     let mut db_params = database::Params::new();
+//! #     db_params
+//! #         .ddl_dict_size(32768)
+//! #         .max_classes(100)
+//! #         .max_indexes(1000);
+//! #     let mut devs = util::DeviceContainer::new();
+//! #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+//! #     let conn = connection::Connection::new(&db)?;
+//! #     let engine = sql::engine::LocalEngine::new(&conn)?;
+//!     engine.execute_statement("CREATE TABLE Src(i integer);", &[])?;
+//!     engine.execute_statement("CREATE TABLE Dst(i integer);", &[])?;
+//!     for i in 0..10000 {
+//!         engine.execute_statement("INSERT INTO Src(i) VALUES(?);", &[&i])?;
+//!     }
+//!
+//!     // A single statement reports the final count only.
+//!     let n = engine.execute_statement("INSERT INTO Dst SELECT i FROM Src;", &[])?;
+//!     assert_eq!(n, 10000);
+//!
+//!     // Batches allow for progress reporting and cancellation.
+//!     let mut on_progress = |rows_so_far: i64| rows_so_far < 5000;
+//!
+//!     let tx = Transaction::begin(&engine, Mode::ReadWrite, 0)?;
+//!     let mut rows_so_far = 0;
+//!     let mut cancelled = false;
+//!     for start in (0..10000).step_by(1000) {
+//!         rows_so_far += tx.execute_statement(
+//!             "INSERT INTO Dst SELECT i FROM Src WHERE i >= ? AND i < ?;",
+//!             &[&start, &(start + 1000)],
+//!         )?;
+//!         if !on_progress(rows_so_far) {
+//!             cancelled = true;
+//!             break;
+//!         }
+//!     }
+//!     assert!(cancelled);
+//!     assert_eq!(rows_so_far, 5000);
+//!     tx.rollback()?;
+//!
+//!     let ds = engine.execute_query("SELECT COUNT(*) FROM Dst;", &[])?.unwrap();
+//!     let mut cur = ds.cursor()?;
+//!     assert!(cur.advance()?);
+//!     let rec = cur.current_record().unwrap();
+//!     assert_eq!(rec.get_at(0)?.to_i64()?, 10000);
+//! #     Ok(())
+//! # }
+//! ```
+//!
+//! [`Engine::execute_statement()`]: ./trait.Engine.html#method.execute_statement
+//! [`Transaction`]: ../trans/struct.Transaction.html
+//!
+//! # Cardinality Estimates
+//!
+//! The SQL engines do not report the estimated number of rows or the cost
+//! of a query before it is executed (see the [SQL API limitations]). The
+//! exact number of rows can be obtained by executing a `SELECT COUNT(*)`
+//! query with the same conditions.
+//!
+//! [SQL API limitations]: ../index.html#sql-api-limitations
 
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::hash::Hash;
 use std::marker::PhantomData;
 use std::mem::MaybeUninit;
+use std::sync::atomic::AtomicU64;
+use std::time::Instant;
 
 use crate::connection::Connection;
-use crate::sql::data_source::DataSource;
+use crate::sql::data_source::{DataSource, Record};
 use crate::sql::stmt::{ExecutionContext, Statement};
-use crate::sql::value::ToValue;
+use crate::sql::trans::{Mode, Transaction, TransactionGuard, TxOptions};
+use crate::sql::value::{FromValue, ToValue, Type};
 use crate::sql::{mcosql_error_code, result_from_code};
-use crate::{exdb_sys, Result};
+use crate::{exdb_sys, mco_ret, Error, Result};
 
 /// The common SQL Engine trait.
 ///
@@ -157,9 +251,53 @@ pub trait Engine {
     /// Returns the internal engine handle.
     fn get_engine(&self) -> exdb_sys::database_t;
 
+    #[doc(hidden)]
+    fn last_statement_slot(&self) -> Option<&RefCell<Option<String>>> {
+        None
+    }
+
+    #[doc(hidden)]
+    fn schema_version_slot(&self) -> Option<&AtomicU64> {
+        None
+    }
+
     /// Executes the SQL statement in the context of the engine.
     ///
-    /// Returns the number of affected rows, if available.
+    /// Returns the number of affected rows, if available. For `INSERT`,
+    /// `UPDATE`, and `DELETE` statements, this is the number of rows
+    /// inserted, modified, or removed by the statement; a zero count can be
+    /// used to detect a no-op update.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use extremedb::sql::engine::Engine;
+    /// # use extremedb::{connection, database, runtime, sql};
+    /// # use extremedb::device::util;
+    /// # fn main() -> extremedb::Result<()> {
+    /// #     let runtime = runtime::Runtime::start(vec![]);
+    /// #     let mut db_params = database::Params::new();
+    /// #     db_params
+    /// #         .ddl_dict_size(32768)
+    /// #         .max_classes(100)
+    /// #         .max_indexes(1000);
+    /// #     let mut devs = util::DeviceContainer::new();
+    /// #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+    /// #     let conn = connection::Connection::new(&db)?;
+    /// #     let engine = sql::engine::LocalEngine::new(&conn)?;
+    ///     engine.execute_statement("CREATE TABLE TestTable(i integer);", &[])?;
+    ///
+    ///     let n = engine.execute_statement("INSERT INTO TestTable VALUES(1), (2), (3);", &[])?;
+    ///     assert_eq!(n, 3);
+    ///
+    ///     let n = engine.execute_statement("UPDATE TestTable SET i = 0 WHERE i > 10;", &[])?;
+    ///     assert_eq!(n, 0);
+    ///
+    ///     let n = engine.execute_statement("DELETE FROM TestTable WHERE i >= 2;", &[])?;
+    ///     assert_eq!(n, 2);
+    /// #     Ok(())
+    /// # }
+    /// ```
     fn execute_statement(&self, sql: &str, args: &[&dyn ToValue]) -> Result<i64> {
         Statement::execute_statement(ExecutionContext::with_engine(self), sql, args)
     }
@@ -167,6 +305,16 @@ pub trait Engine {
     /// Executes the SQL query in the context of the engine.
     ///
     /// Returns the produced data source if available, otherwise `None`.
+    ///
+    /// A running query cannot be interrupted (see the [SQL API limitations]),
+    /// so query execution cannot be limited by a timeout.
+    /// [`LocalEngine::transaction_with()`] can be used with a
+    /// [maximum elapsed time] to discard the results of the transactions
+    /// which took too long, once they complete.
+    ///
+    /// [`LocalEngine::transaction_with()`]: ./struct.LocalEngine.html#method.transaction_with
+    /// [maximum elapsed time]: ../trans/struct.TxOptions.html#method.max_elapsed
+    /// [SQL API limitations]: ../index.html#sql-api-limitations
     fn execute_query<'a>(
         &'a self,
         sql: &str,
@@ -174,6 +322,830 @@ pub trait Engine {
     ) -> Result<Option<DataSource<'a>>> {
         Statement::execute_query(ExecutionContext::with_engine(self), sql, args)
     }
+
+    /// Executes the SQL statement with named parameters in the context of
+    /// the engine.
+    ///
+    /// The parameters are referred to in the statement as `:name`, and are
+    /// passed in `args` as the name-value pairs; the names can be given with
+    /// or without the leading colon. A parameter can be used more than once
+    /// in the statement. Placeholders inside string literals, quoted
+    /// identifiers, and comments are not substituted.
+    ///
+    /// Returns `COMPILE_ERROR` if the statement uses a parameter which is
+    /// not supplied, or if a supplied parameter is not used. Otherwise,
+    /// returns the number of affected rows, if available.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use extremedb::sql::engine::Engine;
+    /// # use extremedb::{connection, database, runtime, sql};
+    /// # use extremedb::device::util;
+    /// # fn main() -> extremedb::Result<()> {
+    /// #     let runtime = runtime::Runtime::start(vec![]);
+    /// #     let mut db_params = database::Params::new();
+    /// #     db_params
+    /// #         .ddl_dict_size(32768)
+    /// #         .max_classes(100)
+    /// #         .max_indexes(1000);
+    /// #     let mut devs = util::DeviceContainer::new();
+    /// #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+    /// #     let conn = connection::Connection::new(&db)?;
+    /// #     let engine = sql::engine::LocalEngine::new(&conn)?;
+    ///     engine.execute_statement("CREATE TABLE TestTable(i integer, j integer, s string);", &[])?;
+    ///
+    ///     engine.execute_named(
+    ///         "INSERT INTO TestTable(i, j, s) VALUES(:id, :id, :name);",
+    ///         &[(":id", &1), (":name", &"Hello")],
+    ///     )?;
+    ///
+    ///     let ds = engine.execute_query("SELECT i, j, s FROM TestTable;", &[])?.unwrap();
+    ///     let mut cur = ds.cursor()?;
+    ///     assert!(cur.advance()?);
+    ///     {
+    ///         let rec = cur.current_record().unwrap();
+    ///         assert_eq!(rec.get_at(0)?.to_i64()?, 1);
+    ///         assert_eq!(rec.get_at(1)?.to_i64()?, 1);
+    ///         assert_eq!(rec.get_at(2)?.to_string()?, "Hello");
+    ///     }
+    ///
+    ///     // The `:name` parameter is missing.
+    ///     let ret = engine.execute_named(
+    ///         "INSERT INTO TestTable(i, j, s) VALUES(:id, :id, :name);",
+    ///         &[("id", &2)],
+    ///     );
+    ///     assert!(ret.is_err());
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn execute_named(&self, sql: &str, args: &[(&str, &dyn ToValue)]) -> Result<i64> {
+        let (sql, values) = Statement::bind_named(sql, args)?;
+        self.execute_statement(&sql, &values)
+    }
+
+    /// Executes the SQL query with named parameters in the context of the
+    /// engine.
+    ///
+    /// The parameters are bound as described for [`execute_named()`].
+    ///
+    /// Returns the produced data source if available, otherwise `None`.
+    ///
+    /// [`execute_named()`]: #method.execute_named
+    fn execute_query_named<'a>(
+        &'a self,
+        sql: &str,
+        args: &[(&str, &dyn ToValue)],
+    ) -> Result<Option<DataSource<'a>>> {
+        let (sql, values) = Statement::bind_named(sql, args)?;
+        self.execute_query(&sql, &values)
+    }
+
+    /// Executes the SQL query and folds the produced records into a single
+    /// value.
+    ///
+    /// The function `f` is called for each record with the accumulated value,
+    /// starting from `init`. Only the current record is accessible at any
+    /// time, so this method can be used to compute aggregates over large
+    /// result sets without materializing them. Iteration stops at the first
+    /// error returned by `f`.
+    ///
+    /// Returns `init` if the query does not produce a data source.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use extremedb::sql::engine::Engine;
+    /// # use extremedb::{connection, database, device, runtime, sql};
+    /// # use extremedb::device::util;
+    /// # fn main() -> extremedb::Result<()> {
+    /// #     let runtime = runtime::Runtime::start(vec![]);
+    /// #     let mut db_params = database::Params::new();
+    /// #     db_params
+    /// #         .ddl_dict_size(32768)
+    /// #         .max_classes(100)
+    /// #         .max_indexes(1000);
+    /// #     let mut devs = util::DeviceContainer::new();
+    /// #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+    /// #     let conn = connection::Connection::new(&db)?;
+    /// #     let engine = sql::engine::LocalEngine::new(&conn)?;
+    ///     engine.execute_statement("CREATE TABLE TestTable(i integer);", &[])?;
+    ///     for i in 0..1000 {
+    ///         engine.execute_statement("INSERT INTO TestTable(i) VALUES(?);", &[&i])?;
+    ///     }
+    ///
+    ///     let sum = engine.query_fold("SELECT i FROM TestTable;", &[], 0, |acc, rec| {
+    ///         Ok(acc + rec.get_at(0)?.to_i64()?)
+    ///     })?;
+    ///
+    ///     let ds = engine.execute_query("SELECT SUM(i) FROM TestTable;", &[])?.unwrap();
+    ///     let mut cur = ds.cursor()?;
+    ///     assert!(cur.advance()?);
+    ///     let rec = cur.current_record().unwrap();
+    ///     assert_eq!(rec.get_at(0)?.to_i64()?, sum);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn query_fold<T, F>(&self, sql: &str, args: &[&dyn ToValue], init: T, mut f: F) -> Result<T>
+    where
+        Self: Sized,
+        F: FnMut(T, &Record) -> Result<T>,
+    {
+        let ds = match self.execute_query(sql, args)? {
+            Some(ds) => ds,
+            None => return Ok(init),
+        };
+
+        let mut cur = ds.cursor()?;
+        let mut acc = init;
+
+        while cur.advance()? {
+            if let Some(rec) = cur.current_record() {
+                acc = f(acc, &rec)?;
+            }
+        }
+
+        Ok(acc)
+    }
+
+    /// Executes the SQL query and collects the produced records into a map.
+    ///
+    /// The query must produce exactly two columns: the first one is used as
+    /// the key, and the second one as the value. This is useful for the
+    /// `GROUP BY` queries.
+    ///
+    /// Returns an `INVALID_OPERATION` error if the query produces a different
+    /// number of columns, and a `NOT_UNIQUE` error if the keys are not
+    /// unique. Returns an empty map if the query does not produce a data
+    /// source.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use extremedb::sql::engine::Engine;
+    /// # use extremedb::{connection, database, device, runtime, sql};
+    /// # use extremedb::device::util;
+    /// # use std::collections::HashMap;
+    /// # fn main() -> extremedb::Result<()> {
+    /// #     let runtime = runtime::Runtime::start(vec![]);
+    /// #     let mut db_params = database::Params::new();
+    /// #     db_params
+    /// #         .ddl_dict_size(32768)
+    /// #         .max_classes(100)
+    /// #         .max_indexes(1000);
+    /// #     let mut devs = util::DeviceContainer::new();
+    /// #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+    /// #     let conn = connection::Connection::new(&db)?;
+    /// #     let engine = sql::engine::LocalEngine::new(&conn)?;
+    ///     engine.execute_statement("CREATE TABLE Sales(region string, amount integer);", &[])?;
+    ///     engine.execute_statement("INSERT INTO Sales VALUES('North', 10);", &[])?;
+    ///     engine.execute_statement("INSERT INTO Sales VALUES('North', 20);", &[])?;
+    ///     engine.execute_statement("INSERT INTO Sales VALUES('South', 5);", &[])?;
+    ///
+    ///     let totals: HashMap<String, i64> = engine.query_map(
+    ///         "SELECT region, SUM(amount) FROM Sales GROUP BY region;",
+    ///         &[],
+    ///     )?;
+    ///
+    ///     assert_eq!(totals.len(), 2);
+    ///     assert_eq!(totals["North"], 30);
+    ///     assert_eq!(totals["South"], 5);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn query_map<K, V>(&self, sql: &str, args: &[&dyn ToValue]) -> Result<HashMap<K, V>>
+    where
+        Self: Sized,
+        K: FromValue + Eq + Hash,
+        V: FromValue,
+    {
+        let mut ret = HashMap::new();
+
+        let ds = match self.execute_query(sql, args)? {
+            Some(ds) => ds,
+            None => return Ok(ret),
+        };
+
+        if ds.n_columns()? != 2 {
+            return Err(Error::new_sql(mcosql_error_code::INVALID_OPERATION));
+        }
+
+        let mut cur = ds.cursor()?;
+
+        while cur.advance()? {
+            if let Some(rec) = cur.current_record() {
+                let key = K::from_value(&*rec.get_at(0)?)?;
+                let val = V::from_value(&*rec.get_at(1)?)?;
+
+                if ret.insert(key, val).is_some() {
+                    return Err(Error::new_sql(mcosql_error_code::NOT_UNIQUE));
+                }
+            }
+        }
+
+        Ok(ret)
+    }
+
+    /// Executes the SQL query which produces a single scalar value, such
+    /// as an aggregate, and converts it to `T`.
+    ///
+    /// Returns `None` in two cases, which are not distinguished:
+    ///
+    /// - The query produces no rows (or no data source at all);
+    /// - The query produces a single row, and its value is SQL `NULL` (for
+    ///   example, `MAX()` of an empty table or of a column containing only
+    ///   `NULL`s).
+    ///
+    /// Returns an `INVALID_OPERATION` error if the query produces more than
+    /// one column, and a `NOT_SINGLE_VALUE` error if it produces more than
+    /// one row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use extremedb::sql::engine::Engine;
+    /// # use extremedb::{connection, database, device, runtime, sql};
+    /// # use extremedb::device::util;
+    /// # fn main() -> extremedb::Result<()> {
+    /// #     let runtime = runtime::Runtime::start(vec![]);
+    /// #     let mut db_params = database::Params::new();
+    /// #     db_params
+    /// #         .ddl_dict_size(32768)
+    /// #         .max_classes(100)
+    /// #         .max_indexes(1000);
+    /// #     let mut devs = util::DeviceContainer::new();
+    /// #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+    /// #     let conn = connection::Connection::new(&db)?;
+    /// #     let engine = sql::engine::LocalEngine::new(&conn)?;
+    ///     engine.execute_statement("CREATE TABLE Events(id integer, v integer);", &[])?;
+    ///
+    ///     // No rows.
+    ///     let v: Option<i64> = engine.query_scalar_opt("SELECT v FROM Events;", &[])?;
+    ///     assert!(v.is_none());
+    ///
+    ///     // A single NULL value.
+    ///     engine.execute_statement("INSERT INTO Events(id, v) VALUES(?, ?);", &[&1, &None::<i64>])?;
+    ///     let max: Option<i64> = engine.query_scalar_opt("SELECT MAX(v) FROM Events;", &[])?;
+    ///     assert!(max.is_none());
+    ///
+    ///     engine.execute_statement("INSERT INTO Events(id, v) VALUES(?, ?);", &[&2, &42])?;
+    ///     let max: Option<i64> = engine.query_scalar_opt("SELECT MAX(v) FROM Events;", &[])?;
+    ///     assert_eq!(max, Some(42));
+    ///
+    ///     // Multiple rows.
+    ///     assert!(engine.query_scalar_opt::<i64>("SELECT id FROM Events;", &[]).is_err());
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn query_scalar_opt<T>(&self, sql: &str, args: &[&dyn ToValue]) -> Result<Option<T>>
+    where
+        Self: Sized,
+        T: FromValue,
+    {
+        let ds = match self.execute_query(sql, args)? {
+            Some(ds) => ds,
+            None => return Ok(None),
+        };
+
+        if ds.n_columns()? != 1 {
+            return Err(Error::new_sql(mcosql_error_code::INVALID_OPERATION));
+        }
+
+        let mut cur = ds.cursor()?;
+
+        if !cur.advance()? {
+            return Ok(None);
+        }
+
+        let ret = match cur.current_record() {
+            Some(rec) => {
+                let val = rec.get_at(0)?;
+                if val.is_null() {
+                    None
+                } else {
+                    Some(T::from_value(&val)?)
+                }
+            }
+            None => None,
+        };
+
+        if cur.advance()? {
+            return Err(Error::new_sql(mcosql_error_code::NOT_SINGLE_VALUE));
+        }
+
+        Ok(ret)
+    }
+
+    /// Classifies the SQL statement without executing it.
+    ///
+    /// There is no statement preparation step separate from the execution
+    /// (see the [SQL API limitations]), so the classification is a heuristic
+    /// based on the leading keyword of the statement; whitespace, comments,
+    /// and opening parentheses preceding it are skipped. For the statements starting
+    /// with a `WITH` clause, the first `SELECT`, `INSERT`, `UPDATE`, or
+    /// `DELETE` keyword following the common table expressions is used.
+    /// The statement is not validated, so a malformed statement can still
+    /// be classified successfully.
+    ///
+    /// The statements which do not belong to any of the specific kinds (for
+    /// example, transaction control statements like `COMMIT`, or `EXPLAIN`)
+    /// are classified as [`StatementKind::Other`]. Returns a
+    /// `COMPILE_ERROR` if the statement text contains no keyword at all.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use extremedb::sql::engine::{Engine, StatementKind};
+    /// # use extremedb::{connection, database, device, runtime, sql};
+    /// # use extremedb::device::util;
+    /// # fn main() -> extremedb::Result<()> {
+    /// #     let runtime = runtime::Runtime::start(vec![]);
+    /// #     let mut db_params = database::Params::new();
+    /// #     db_params
+    /// #         .ddl_dict_size(32768)
+    /// #         .max_classes(100)
+    /// #         .max_indexes(1000);
+    /// #     let mut devs = util::DeviceContainer::new();
+    /// #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+    /// #     let conn = connection::Connection::new(&db)?;
+    /// #     let engine = sql::engine::LocalEngine::new(&conn)?;
+    ///     assert_eq!(
+    ///         engine.statement_kind("SELECT * FROM TestTable;")?,
+    ///         StatementKind::Query
+    ///     );
+    ///     assert_eq!(
+    ///         engine.statement_kind("INSERT INTO TestTable VALUES(1);")?,
+    ///         StatementKind::Dml
+    ///     );
+    ///     assert_eq!(
+    ///         engine.statement_kind("CREATE TABLE TestTable(i integer);")?,
+    ///         StatementKind::Ddl
+    ///     );
+    ///     assert_eq!(
+    ///         engine.statement_kind("WITH t AS (SELECT 1) INSERT INTO TestTable SELECT * FROM t;")?,
+    ///         StatementKind::Dml
+    ///     );
+    ///     assert_eq!(engine.statement_kind("COMMIT;")?, StatementKind::Other);
+    ///     assert!(engine.statement_kind("  ").is_err());
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`StatementKind::Other`]: ./enum.StatementKind.html#variant.Other
+    /// [SQL API limitations]: ../index.html#sql-api-limitations
+    fn statement_kind(&self, sql: &str) -> Result<StatementKind> {
+        Statement::kind(sql)
+    }
+
+    /// Creates a table described by the `spec`.
+    ///
+    /// The `CREATE TABLE` statement is generated from the specification,
+    /// with all the identifiers validated and quoted. This method fails with
+    /// a `COMPILE_ERROR` if the specification is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use extremedb::sql::engine::{Engine, TableSpec};
+    /// # use extremedb::sql::value::Type;
+    /// # use extremedb::{connection, database, device, runtime, sql};
+    /// # use extremedb::device::util;
+    /// # fn main() -> extremedb::Result<()> {
+    /// #     let runtime = runtime::Runtime::start(vec![]);
+    /// #     let mut db_params = database::Params::new();
+    /// #     db_params
+    /// #         .ddl_dict_size(32768)
+    /// #         .max_classes(100)
+    /// #         .max_indexes(1000);
+    /// #     let mut devs = util::DeviceContainer::new();
+    /// #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+    /// #     let conn = connection::Connection::new(&db)?;
+    /// #     let engine = sql::engine::LocalEngine::new(&conn)?;
+    ///     let mut spec = TableSpec::new("Sensor");
+    ///     spec.column("id", Type::Int8, false)
+    ///         .column("name", Type::String, false)
+    ///         .column("reading", Type::Real8, true)
+    ///         .column("updated", Type::Time, true)
+    ///         .primary_key("id");
+    ///
+    ///     engine.create_table(spec)?;
+    ///
+    ///     engine.execute_statement(
+    ///         "INSERT INTO Sensor(id, name, reading) VALUES(?, ?, ?);",
+    ///         &[&1, &"Temperature", &21.5],
+    ///     )?;
+    ///
+    ///     let ds = engine.execute_query("SELECT name FROM Sensor WHERE id = 1;", &[])?.unwrap();
+    ///     let mut cur = ds.cursor()?;
+    ///     assert!(cur.advance()?);
+    ///     let rec = cur.current_record().unwrap();
+    ///     assert_eq!(rec.get_at(0)?.to_string()?, "Temperature");
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn create_table(&self, spec: TableSpec) -> Result<()> {
+        let ddl = spec.to_ddl()?;
+        self.execute_statement(&ddl, &[]).and(Ok(()))
+    }
+
+    /// Executes an `UPDATE` statement, and reports whether it matched any
+    /// rows.
+    ///
+    /// The outcome is derived from the affected row count returned by the
+    /// SQL engine: [`UpdateOutcome::NotFound`] is returned if no rows were
+    /// matched, and [`UpdateOutcome::Updated`] otherwise. Depending on the
+    /// engine, a row which was matched, but whose values were not actually
+    /// changed (e.g. assigned the values it already had), may still be
+    /// counted as updated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use extremedb::sql::engine::{Engine, UpdateOutcome};
+    /// # use extremedb::{connection, database, device, runtime, sql};
+    /// # use extremedb::device::util;
+    /// # fn main() -> extremedb::Result<()> {
+    /// #     let runtime = runtime::Runtime::start(vec![]);
+    /// #     let mut db_params = database::Params::new();
+    /// #     db_params
+    /// #         .ddl_dict_size(32768)
+    /// #         .max_classes(100)
+    /// #         .max_indexes(1000);
+    /// #     let mut devs = util::DeviceContainer::new();
+    /// #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+    /// #     let conn = connection::Connection::new(&db)?;
+    /// #     let engine = sql::engine::LocalEngine::new(&conn)?;
+    ///     engine.execute_statement("CREATE TABLE TestTable(id integer primary key, s string);", &[])?;
+    ///     engine.execute_statement("INSERT INTO TestTable(id, s) VALUES(1, 'Hello');", &[])?;
+    ///
+    ///     let sql = "UPDATE TestTable SET s = ? WHERE id = ?;";
+    ///
+    ///     assert_eq!(engine.update_one(sql, &[&"World", &1])?, UpdateOutcome::Updated(1));
+    ///     assert_eq!(engine.update_one(sql, &[&"World", &2])?, UpdateOutcome::NotFound);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`UpdateOutcome::NotFound`]: ./enum.UpdateOutcome.html#variant.NotFound
+    /// [`UpdateOutcome::Updated`]: ./enum.UpdateOutcome.html#variant.Updated
+    fn update_one(&self, sql: &str, args: &[&dyn ToValue]) -> Result<UpdateOutcome> {
+        let n = self.execute_statement(sql, args)?;
+
+        if n > 0 {
+            Ok(UpdateOutcome::Updated(n as u64))
+        } else {
+            Ok(UpdateOutcome::NotFound)
+        }
+    }
+
+    /// Inserts a row into the table `table`.
+    ///
+    /// The column names and the values are provided by the [`ToRow`]
+    /// implementation of `row`, and are bound as the statement parameters.
+    /// The table and column names must be valid SQL identifiers, as
+    /// described for [`TableSpec`]; otherwise, or if `row` has no columns,
+    /// `COMPILE_ERROR` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use extremedb::sql::engine::{Engine, ToRow};
+    /// # use extremedb::sql::value::ToValue;
+    /// # use extremedb::{connection, database, device, runtime, sql};
+    /// # use extremedb::device::util;
+    /// struct Sensor {
+    ///     id: i64,
+    ///     name: String,
+    /// }
+    ///
+    /// impl ToRow for Sensor {
+    ///     fn columns() -> &'static [&'static str] {
+    ///         &["id", "name"]
+    ///     }
+    ///
+    ///     fn values(&self) -> Vec<&dyn ToValue> {
+    ///         vec![&self.id, &self.name]
+    ///     }
+    /// }
+    ///
+    /// # fn main() -> extremedb::Result<()> {
+    /// #     let runtime = runtime::Runtime::start(vec![]);
+    /// #     let mut db_params = database::Params::new();
+    /// #     db_params
+    /// #         .ddl_dict_size(32768)
+    /// #         .max_classes(100)
+    /// #         .max_indexes(1000);
+    /// #     let mut devs = util::DeviceContainer::new();
+    /// #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+    /// #     let conn = connection::Connection::new(&db)?;
+    /// #     let engine = sql::engine::LocalEngine::new(&conn)?;
+    ///     engine.execute_statement("CREATE TABLE Sensor(id integer, name string);", &[])?;
+    ///
+    ///     let sensor = Sensor {
+    ///         id: 1,
+    ///         name: "Temperature".to_string(),
+    ///     };
+    ///     engine.insert("Sensor", &sensor)?;
+    ///
+    ///     let ds = engine.execute_query("SELECT id, name FROM Sensor;", &[])?.unwrap();
+    ///     let mut cur = ds.cursor()?;
+    ///     assert!(cur.advance()?);
+    ///     let rec = cur.current_record().unwrap();
+    ///     assert_eq!(rec.get_at(0)?.to_i64()?, 1);
+    ///     assert_eq!(rec.get_at(1)?.to_string()?, "Temperature");
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`ToRow`]: ./trait.ToRow.html
+    /// [`TableSpec`]: ./struct.TableSpec.html
+    fn insert<R: ToRow>(&self, table: &str, row: &R) -> Result<()>
+    where
+        Self: Sized,
+    {
+        let sql = insert_sql(table, R::columns())?;
+        self.execute_statement(&sql, &row.values()).and(Ok(()))
+    }
+
+    /// Returns the SQL text of the last statement or query executed by
+    /// the engine if it failed, or `None` if it succeeded or nothing has
+    /// been executed yet.
+    ///
+    /// The text is recorded exactly as it was passed to the engine (with
+    /// the parameter placeholders). It is only copied when the execution
+    /// fails; a successful execution clears it. The statements executed in
+    /// the transactions started by the engine are recorded as well.
+    ///
+    /// Engines which do not provide storage for the statement text always
+    /// return `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use extremedb::sql::engine::Engine;
+    /// # use extremedb::{connection, database, device, runtime, sql};
+    /// # use extremedb::device::util;
+    /// # fn main() -> extremedb::Result<()> {
+    /// #     let runtime = runtime::Runtime::start(vec![]);
+    /// #     let mut db_params = database::Params::new();
+    /// #     db_params
+    /// #         .ddl_dict_size(32768)
+    /// #         .max_classes(100)
+    /// #         .max_indexes(1000);
+    /// #     let mut devs = util::DeviceContainer::new();
+    /// #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+    /// #     let conn = connection::Connection::new(&db)?;
+    /// #     let engine = sql::engine::LocalEngine::new(&conn)?;
+    ///     assert_eq!(engine.last_statement(), None);
+    ///
+    ///     engine.execute_statement("CREATE TABLE TestTable(i integer);", &[])?;
+    ///     assert_eq!(engine.last_statement(), None);
+    ///
+    ///     let sql = "INSERT INTO MissingTable(i) VALUES(?);";
+    ///     assert!(engine.execute_statement(sql, &[&1]).is_err());
+    ///     assert_eq!(engine.last_statement().as_deref(), Some(sql));
+    ///
+    ///     engine.execute_statement("INSERT INTO TestTable(i) VALUES(?);", &[&1])?;
+    ///     assert_eq!(engine.last_statement(), None);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn last_statement(&self) -> Option<String> {
+        self.last_statement_slot()
+            .and_then(|slot| slot.borrow().clone())
+    }
+}
+
+/// The kind of an SQL statement.
+///
+/// See [`Engine::statement_kind()`] for the details of the classification.
+///
+/// [`Engine::statement_kind()`]: ./trait.Engine.html#method.statement_kind
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StatementKind {
+    /// A query producing a data source (`SELECT`).
+    Query,
+
+    /// A data manipulation statement (`INSERT`, `UPDATE`, `DELETE`, or
+    /// `TRUNCATE`).
+    Dml,
+
+    /// A data definition statement (`CREATE`, `DROP`, or `ALTER`).
+    Ddl,
+
+    /// Any other statement, such as a transaction control statement,
+    /// `SET`, or `EXPLAIN`.
+    Other,
+}
+
+/// The outcome of an [`Engine::update_one()`] call.
+///
+/// [`Engine::update_one()`]: ./trait.Engine.html#method.update_one
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum UpdateOutcome {
+    /// The statement did not match any rows.
+    NotFound,
+
+    /// The statement matched the given number of rows.
+    Updated(u64),
+}
+
+/// A trait for converting a Rust value to a table row.
+///
+/// A row is described by the names of its columns and the values of the
+/// columns in the same order. Rows are inserted into the tables using
+/// [`Engine::insert()`].
+///
+/// [`Engine::insert()`]: ./trait.Engine.html#method.insert
+pub trait ToRow {
+    /// Returns the names of the row's columns.
+    fn columns() -> &'static [&'static str];
+
+    /// Returns the values of the row's columns, in the order of
+    /// [`columns()`].
+    ///
+    /// [`columns()`]: #tymethod.columns
+    fn values(&self) -> Vec<&dyn ToValue>;
+}
+
+/// Derive macro for the [`ToRow`] trait.
+///
+/// The trait can be derived for structures with named fields. Each field is
+/// stored in the column with the same name, in the order of declaration;
+/// the field types must implement [`ToValue`]. The column name can be
+/// overridden using the `#[extremedb(rename = "name")]` field attribute.
+///
+/// This macro is only available when the `derive` feature is enabled.
+///
+/// # Examples
+///
+/// ```
+/// # use extremedb::sql::data_source::FromRow;
+/// # use extremedb::sql::engine::{Engine, ToRow};
+/// # use extremedb::{connection, database, runtime, sql};
+/// # use extremedb::device::util;
+/// #[derive(Debug, PartialEq, FromRow, ToRow)]
+/// struct MyRow {
+///     id: i64,
+///     #[extremedb(rename = "s")]
+///     name: String,
+///     reading: Option<f64>,
+/// }
+///
+/// # fn main() -> extremedb::Result<()> {
+/// #     let runtime = runtime::Runtime::start(vec![]);
+/// #     let mut db_params = database::Params::new();
+/// #     db_params
+/// #         .ddl_dict_size(32768)
+/// #         .max_classes(100)
+/// #         .max_indexes(1000);
+/// #     let mut devs = util::DeviceContainer::new();
+/// #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+/// #     let conn = connection::Connection::new(&db)?;
+/// #     let engine = sql::engine::LocalEngine::new(&conn)?;
+///     engine.execute_statement(
+///         "CREATE TABLE TestTable(reading double, s string, id integer);",
+///         &[],
+///     )?;
+///
+///     assert_eq!(MyRow::columns(), &["id", "s", "reading"]);
+///
+///     let row = MyRow {
+///         id: 1,
+///         name: "Hello".to_string(),
+///         reading: Some(2.5),
+///     };
+///     engine.insert("TestTable", &row)?;
+///
+///     let ds = engine.execute_query("SELECT * FROM TestTable;", &[])?.unwrap();
+///     let rows = ds
+///         .cursor()?
+///         .into_rows::<MyRow>()
+///         .collect::<extremedb::Result<Vec<_>>>()?;
+///
+///     assert_eq!(rows, vec![row]);
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [`ToRow`]: ./trait.ToRow.html
+/// [`ToValue`]: ../value/trait.ToValue.html
+#[cfg(feature = "derive")]
+pub use extremedb_derive::ToRow;
+
+/// A table specification.
+///
+/// This builder describes a table to be created using
+/// [`Engine::create_table()`]. The table and column names must be valid SQL
+/// identifiers: they must start with a letter or an underscore, and contain
+/// only ASCII letters, digits, and underscores.
+///
+/// Only the scalar column types, strings, binaries, and blobs are supported.
+///
+/// [`Engine::create_table()`]: ./trait.Engine.html#method.create_table
+pub struct TableSpec {
+    name: String,
+    columns: Vec<(String, Type, bool)>,
+    primary_key: Option<String>,
+}
+
+impl TableSpec {
+    /// Creates a new specification for the table `name` with no columns.
+    pub fn new(name: &str) -> Self {
+        TableSpec {
+            name: name.to_string(),
+            columns: Vec::new(),
+            primary_key: None,
+        }
+    }
+
+    /// Adds a column of type `ty`.
+    ///
+    /// If `nullable` is `false`, the column is declared `NOT NULL`.
+    pub fn column(&mut self, name: &str, ty: Type, nullable: bool) -> &mut Self {
+        self.columns.push((name.to_string(), ty, nullable));
+        self
+    }
+
+    /// Sets the primary key column.
+    ///
+    /// The column must be added to the specification.
+    pub fn primary_key(&mut self, name: &str) -> &mut Self {
+        self.primary_key = Some(name.to_string());
+        self
+    }
+
+    pub(crate) fn to_ddl(&self) -> Result<String> {
+        if self.columns.is_empty() {
+            return Err(Error::new_sql(mcosql_error_code::COMPILE_ERROR));
+        }
+
+        let mut defs = Vec::with_capacity(self.columns.len() + 1);
+
+        for (name, ty, nullable) in &self.columns {
+            let ty_name = sql_type_name(*ty)?;
+            let null = if *nullable { "" } else { " NOT NULL" };
+            defs.push(format!("{} {}{}", quote_ident(name)?, ty_name, null));
+        }
+
+        if let Some(pk) = &self.primary_key {
+            if !self.columns.iter().any(|(name, _, _)| name == pk) {
+                return Err(Error::new_sql(mcosql_error_code::COMPILE_ERROR));
+            }
+            defs.push(format!("PRIMARY KEY({})", quote_ident(pk)?));
+        }
+
+        Ok(format!(
+            "CREATE TABLE {}({});",
+            quote_ident(&self.name)?,
+            defs.join(", ")
+        ))
+    }
+}
+
+fn insert_sql(table: &str, columns: &[&str]) -> Result<String> {
+    if columns.is_empty() {
+        return Err(Error::new_sql(mcosql_error_code::COMPILE_ERROR));
+    }
+
+    let names = columns
+        .iter()
+        .map(|name| quote_ident(name))
+        .collect::<Result<Vec<_>>>()?;
+    let params = vec!["?"; columns.len()];
+
+    Ok(format!(
+        "INSERT INTO {}({}) VALUES({});",
+        quote_ident(table)?,
+        names.join(", "),
+        params.join(", ")
+    ))
+}
+
+fn quote_ident(ident: &str) -> Result<String> {
+    let mut chars = ident.chars();
+
+    let valid = match chars.next() {
+        Some(c) if c.is_ascii_alphabetic() || c == '_' => {
+            chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        }
+        _ => false,
+    };
+
+    if valid {
+        Ok(format!("\"{}\"", ident))
+    } else {
+        Err(Error::new_sql(mcosql_error_code::COMPILE_ERROR))
+    }
+}
+
+fn sql_type_name(ty: Type) -> Result<&'static str> {
+    match ty {
+        Type::Null | Type::Array | Type::DataSource | Type::List | Type::Sequence => {
+            Err(Error::new_sql(mcosql_error_code::COMPILE_ERROR))
+        }
+        _ => Ok(ty.sql_name()),
+    }
 }
 
 /// A local SQL engine.
@@ -185,6 +1157,8 @@ pub trait Engine {
 pub struct LocalEngine<'a> {
     conn: PhantomData<&'a Connection<'a>>,
     pub(crate) h: exdb_sys::database_t,
+    last_statement: RefCell<Option<String>>,
+    schema_version: &'a AtomicU64,
 }
 
 impl<'a> LocalEngine<'a> {
@@ -197,8 +1171,159 @@ impl<'a> LocalEngine<'a> {
             .and(Ok(LocalEngine {
                 conn: PhantomData,
                 h: unsafe { h.assume_init() },
+                last_statement: RefCell::new(None),
+                schema_version: conn.database().schema_version_slot(),
             }))
     }
+
+    /// Starts a new transaction with the default priority.
+    ///
+    /// The transaction is started using [`Transaction::begin()`], and
+    /// wrapped in a [`TransactionGuard`]. The statements executed using the
+    /// returned guard are grouped atomically: they take effect when the
+    /// transaction is committed, and are discarded if it is rolled back.
+    /// Unlike a plain [`Transaction`], the guarded transaction is committed
+    /// automatically when the guard is dropped, unless it was rolled back.
+    ///
+    /// Note that this includes leaving the scope early, e.g. by returning an
+    /// error with the `?` operator: the statements executed up to that
+    /// point are committed. Call [`commit()`] explicitly once all the
+    /// statements have succeeded, and [`rollback()`] on failure, if the
+    /// partial work must not be committed; an explicit `commit()` also
+    /// reports the commit errors, which are ignored when the guard is
+    /// dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use extremedb::sql::engine::Engine;
+    /// # use extremedb::sql::trans::Mode;
+    /// # use extremedb::{connection, database, device, runtime, sql};
+    /// # use extremedb::device::util;
+    /// # fn main() -> extremedb::Result<()> {
+    /// #     let runtime = runtime::Runtime::start(vec![]);
+    /// #     let mut db_params = database::Params::new();
+    /// #     db_params
+    /// #         .ddl_dict_size(32768)
+    /// #         .max_classes(100)
+    /// #         .max_indexes(1000);
+    /// #     let mut devs = util::DeviceContainer::new();
+    /// #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+    /// #     let conn = connection::Connection::new(&db)?;
+    /// #     let engine = sql::engine::LocalEngine::new(&conn)?;
+    ///     engine.execute_statement("CREATE TABLE TestTable(i integer);", &[])?;
+    ///
+    ///     let txn = engine.begin_transaction(Mode::ReadWrite)?;
+    ///     txn.execute_statement("INSERT INTO TestTable(i) VALUES(?);", &[&1])?;
+    ///     txn.execute_statement("INSERT INTO TestTable(i) VALUES(?);", &[&2])?;
+    ///     txn.rollback()?;
+    ///
+    ///     {
+    ///         let ds = engine.execute_query("SELECT COUNT(*) FROM TestTable;", &[])?.unwrap();
+    ///         let mut cur = ds.cursor()?;
+    ///         assert!(cur.advance()?);
+    ///         assert_eq!(cur.current_record().unwrap().get_at(0)?.to_i64()?, 0);
+    ///     }
+    ///
+    ///     {
+    ///         let txn = engine.begin_transaction(Mode::ReadWrite)?;
+    ///         txn.execute_statement("INSERT INTO TestTable(i) VALUES(?);", &[&3])?;
+    ///         // The transaction is committed here.
+    ///     }
+    ///
+    ///     let ds = engine.execute_query("SELECT COUNT(*) FROM TestTable;", &[])?.unwrap();
+    ///     let mut cur = ds.cursor()?;
+    ///     assert!(cur.advance()?);
+    ///     assert_eq!(cur.current_record().unwrap().get_at(0)?.to_i64()?, 1);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Transaction::begin()`]: ../trans/struct.Transaction.html#method.begin
+    /// [`TransactionGuard`]: ../trans/struct.TransactionGuard.html
+    /// [`Transaction`]: ../trans/struct.Transaction.html
+    /// [`commit()`]: ../trans/struct.TransactionGuard.html#method.commit
+    /// [`rollback()`]: ../trans/struct.TransactionGuard.html#method.rollback
+    pub fn begin_transaction(&self, mode: Mode) -> Result<TransactionGuard<'_>> {
+        Transaction::begin(self, mode, 0).map(TransactionGuard::new)
+    }
+
+    /// Runs `f` in a transaction configured according to `opts`.
+    ///
+    /// The transaction is committed if `f` succeeds, and rolled back if it
+    /// returns an error.
+    ///
+    /// If the [maximum elapsed time] is set, it is checked after `f`
+    /// returns: the running transaction is not interrupted, but if it took
+    /// longer, it is rolled back instead of being committed, and the
+    /// `MCO_E_LONG_TRANSACTION` error is returned.
+    ///
+    /// This method is only available for the local engine, since the
+    /// transactions cannot be started explicitly using the remote engine.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use extremedb::sql::engine::Engine;
+    /// # use extremedb::sql::trans::{Mode, TxOptions};
+    /// # use extremedb::{connection, database, device, runtime, sql};
+    /// # use extremedb::device::util;
+    /// # fn main() -> extremedb::Result<()> {
+    /// #     let runtime = runtime::Runtime::start(vec![]);
+    /// #     let mut db_params = database::Params::new();
+    /// #     db_params
+    /// #         .ddl_dict_size(32768)
+    /// #         .max_classes(100)
+    /// #         .max_indexes(1000);
+    /// #     let mut devs = util::DeviceContainer::new();
+    /// #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+    /// #     let conn = connection::Connection::new(&db)?;
+    /// #     let engine = sql::engine::LocalEngine::new(&conn)?;
+    /// #     engine.execute_statement("CREATE TABLE TestTable(i integer);", &[])?;
+    /// #     engine.execute_statement("INSERT INTO TestTable(i) VALUES(?);", &[&1])?;
+    ///     let mut opts = TxOptions::new();
+    ///     opts.mode(Mode::ReadOnly).priority(1);
+    ///
+    ///     let count = engine.transaction_with(opts, |txn| {
+    ///         let ds = txn.execute_query("SELECT COUNT(*) FROM TestTable;", &[])?;
+    ///         let ds = ds.unwrap();
+    ///         let mut cur = ds.cursor()?;
+    ///         assert!(cur.advance()?);
+    ///         let rec = cur.current_record().unwrap();
+    ///         let count = rec.get_at(0)?.to_i64()?;
+    ///         Ok(count)
+    ///     })?;
+    ///
+    ///     assert_eq!(count, 1);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [maximum elapsed time]: ../trans/struct.TxOptions.html#method.max_elapsed
+    pub fn transaction_with<T, F>(&self, opts: TxOptions, f: F) -> Result<T>
+    where
+        F: FnOnce(&Transaction) -> Result<T>,
+    {
+        let started = Instant::now();
+        let txn = Transaction::begin(self, opts.mode, opts.priority)?;
+
+        let ret = match f(&txn) {
+            Ok(ret) => ret,
+            Err(e) => {
+                // The error returned by `f` takes precedence.
+                let _ = txn.rollback();
+                return Err(e);
+            }
+        };
+
+        match opts.max_elapsed {
+            Some(max) if started.elapsed() > max => {
+                txn.rollback()?;
+                Err(Error::new_core(mco_ret::MCO_E_LONG_TRANSACTION))
+            }
+            _ => txn.commit().and(Ok(ret)),
+        }
+    }
 }
 
 impl<'a> Drop for LocalEngine<'a> {
@@ -214,6 +1339,14 @@ impl<'a> Engine for LocalEngine<'a> {
     fn get_engine(&self) -> exdb_sys::database_t {
         self.h
     }
+
+    fn last_statement_slot(&self) -> Option<&RefCell<Option<String>>> {
+        Some(&self.last_statement)
+    }
+
+    fn schema_version_slot(&self) -> Option<&AtomicU64> {
+        Some(self.schema_version)
+    }
 }
 
 /// A local SQL engine reference.
@@ -231,6 +1364,7 @@ impl<'a> Engine for LocalEngine<'a> {
 pub struct LocalEngineRef<'a> {
     engine: PhantomData<&'a LocalEngine<'a>>,
     pub(crate) h: exdb_sys::database_t,
+    schema_version: &'a AtomicU64,
 }
 
 impl<'a> LocalEngineRef<'a> {
@@ -241,6 +1375,7 @@ impl<'a> LocalEngineRef<'a> {
         LocalEngineRef {
             engine: PhantomData,
             h: engine.h,
+            schema_version: engine.schema_version,
         }
     }
 
@@ -256,6 +1391,7 @@ impl<'a> LocalEngineRef<'a> {
         LocalEngineRef {
             engine: PhantomData,
             h: engine.h,
+            schema_version: &*(engine.schema_version as *const AtomicU64),
         }
     }
 }
@@ -271,6 +1407,8 @@ unsafe impl Send for LocalEngineRef<'_> {}
 pub struct LocalEngineSession<'a> {
     engine: PhantomData<LocalEngineRef<'a>>,
     h: exdb_sys::mcosql_rs_session,
+    last_statement: RefCell<Option<String>>,
+    schema_version: &'a AtomicU64,
 }
 
 impl<'a> LocalEngineSession<'a> {
@@ -284,6 +1422,8 @@ impl<'a> LocalEngineSession<'a> {
         .and(Ok(LocalEngineSession {
             engine: PhantomData,
             h: unsafe { h.assume_init() },
+            last_statement: RefCell::new(None),
+            schema_version: engine_ref.schema_version,
         }))
     }
 }
@@ -294,6 +1434,14 @@ impl<'a> Engine for LocalEngineSession<'a> {
         // its pointer here.
         self.h as exdb_sys::database_t
     }
+
+    fn last_statement_slot(&self) -> Option<&RefCell<Option<String>>> {
+        Some(&self.last_statement)
+    }
+
+    fn schema_version_slot(&self) -> Option<&AtomicU64> {
+        Some(self.schema_version)
+    }
 }
 
 impl<'a> Drop for LocalEngineSession<'a> {
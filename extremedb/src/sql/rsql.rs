@@ -7,6 +7,16 @@
 //! Remote SQL.
 //!
 //! This module is only available when the `rsql` feature is enabled.
+//!
+//! # Security
+//!
+//! The remote SQL client and server communicate over plain TCP. The
+//! underlying *e*X*treme*DB remote SQL API does not support TLS or other
+//! means of encrypting or authenticating the connections, so neither the
+//! client nor the server can be configured to use them. To connect across
+//! untrusted networks, the connections have to be secured externally, for
+//! instance, using a TLS or SSH tunnel between the client and the server
+//! hosts.
 
 pub mod client;
 pub mod server;
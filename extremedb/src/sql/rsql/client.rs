@@ -108,6 +108,8 @@
 //! # }
 //! ```
 
+use std::cell::RefCell;
+use std::ffi::CString;
 use std::marker::PhantomData;
 use std::mem::MaybeUninit;
 
@@ -115,7 +117,7 @@ use crate::runtime::Runtime;
 
 use crate::sql::engine::Engine;
 use crate::sql::{mcosql_error_code, result_from_code};
-use crate::{exdb_sys, Result};
+use crate::{exdb_sys, Error, Result};
 
 /// Client connection parameters.
 pub struct Params {
@@ -139,6 +141,14 @@ impl Params {
     /// Sets the transmit buffer size.
     ///
     /// Default value is 64 KiB.
+    ///
+    /// The remote SQL client transfers query results in buffer-sized
+    /// portions, so this value determines how many rows are pulled from the
+    /// server per network round trip. Larger buffers reduce the number of
+    /// round trips for large result sets at the cost of the client memory.
+    ///
+    /// Note that the underlying *e*X*treme*DB remote SQL client API does not
+    /// allow specifying the number of rows fetched per round trip directly.
     pub fn tx_buf_size(&mut self, tx_buf_size: usize) -> &mut Self {
         self.tx_buf_size = tx_buf_size;
         self
@@ -179,10 +189,23 @@ impl Params {
 pub struct RemoteEngine<'a> {
     runtime: PhantomData<&'a Runtime>,
     h: exdb_sys::database_t,
+    last_statement: RefCell<Option<String>>,
 }
 
 impl<'a> RemoteEngine<'a> {
-    pub fn connect(_runtime: &'a Runtime, params: Params) -> Result<Self> {
+    /// Connects to the remote SQL server using the connection parameters.
+    ///
+    /// See also [`RemoteEngineBuilder`].
+    ///
+    /// [`RemoteEngineBuilder`]: ./struct.RemoteEngineBuilder.html
+    pub fn connect(runtime: &'a Runtime, params: Params) -> Result<Self> {
+        RemoteEngine::connect_with(runtime, &params)
+    }
+
+    fn connect_with(_runtime: &'a Runtime, params: &Params) -> Result<Self> {
+        let host = CString::new(params.host.as_str())
+            .or(Err(Error::new_sql(mcosql_error_code::SQL_INVALID_OPERAND)))?;
+
         let mut h = MaybeUninit::uninit();
 
         result_from_code(unsafe {
@@ -194,7 +217,7 @@ impl<'a> RemoteEngine<'a> {
         result_from_code(unsafe {
             let rc = exdb_sys::sqlcln_open(
                 h,
-                params.host.as_ptr() as *const i8,
+                host.as_ptr(),
                 params.port as i32,
                 params.max_conn_attempts as i32,
             );
@@ -209,10 +232,165 @@ impl<'a> RemoteEngine<'a> {
         Ok(RemoteEngine {
             runtime: PhantomData,
             h,
+            last_statement: RefCell::new(None),
         })
     }
 }
 
+/// Remote SQL engine builder.
+///
+/// This builder collects the client connection parameters and connects to
+/// the remote SQL server.
+///
+/// Only the options supported by the underlying *e*X*treme*DB remote SQL
+/// client API are available. In particular, the client API does not support
+/// authentication, encrypted connections, or connection timeouts; the time
+/// spent connecting to the server is limited by the
+/// [number of connection attempts].
+///
+/// # Examples
+///
+/// ```
+/// # use extremedb::sql::engine::{Engine, LocalEngine, LocalEngineRef};
+/// # use extremedb::sql::rsql::client::RemoteEngineBuilder;
+/// # use extremedb::sql::rsql::server::{self, Server};
+/// # use extremedb::{connection, database, device, runtime, sql};
+/// # use extremedb::device::util;
+/// # use std::sync::Arc;
+/// # use std::thread;
+/// # use std::time::Duration;
+/// #
+/// # fn server_try_create<'a>(engine: &'a LocalEngine, port: u16) -> Option<Server<'a>> {
+/// #     let eref = LocalEngineRef::new(&engine);
+/// #     let res = Server::create(eref, server::Params::new(port));
+/// #     if res.is_ok() {
+/// #         let mut srv = res.unwrap();
+/// #         let res = srv.start();
+/// #         if res.is_ok() {
+/// #             thread::sleep(Duration::from_secs(3));
+/// #             Some(srv)
+/// #         } else {
+/// #             None
+/// #         }
+/// #     } else {
+/// #         None
+/// #     }
+/// # }
+/// #
+/// # fn client_proc(runtime: &runtime::Runtime, port: u16) -> extremedb::Result<()> {
+///     // let port = ...
+///
+///     let rsql = RemoteEngineBuilder::new(&runtime, "localhost", port)
+///         .tx_buf_size(256 * 1024)
+///         .max_conn_attempts(3)
+///         .build()?;
+///
+///     rsql.execute_statement("CREATE TABLE TestTable(i int, s string);", &[])?;
+///     rsql.execute_statement("INSERT INTO TestTable(i, s) VALUES(1, 'Hello');", &[])?;
+/// #
+/// #     Ok(())
+/// # }
+/// #
+/// # fn main() -> extremedb::Result<()> {
+/// #     let runtime = Arc::new(runtime::Runtime::start(vec![]));
+/// #     let mut db_params = database::Params::new();
+/// #     db_params
+/// #         .ddl_dict_size(32768)
+/// #         .max_classes(100)
+/// #         .max_indexes(1000);
+/// #     let mut devs = util::DeviceContainer::new();
+/// #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+/// #     let conn = connection::Connection::new(&db)?;
+/// #     let engine = sql::engine::LocalEngine::new(&conn)?;
+/// #
+/// #     let mut port = 25223;
+/// #     let mut attempts = 10;
+/// #
+/// #     let mut srv = None;
+/// #     while srv.is_none() && attempts > 0 {
+/// #         srv = server_try_create(&engine, port);
+/// #
+/// #         if srv.is_none() {
+/// #             port += 1;
+/// #             attempts -= 1;
+/// #         }
+/// #     }
+/// #
+/// #     let mut srv = srv.expect("Failed to create the server");
+/// #
+/// #     let rt = runtime.clone();
+/// #     let t_cli = thread::spawn(move || {
+/// #         client_proc(&rt, port).expect("Client failed");
+/// #     });
+/// #
+/// #     t_cli.join().expect("Failed to join the client thread");
+/// #
+/// #     srv.stop()?;
+/// #
+/// #     let ds = engine.execute_query("SELECT COUNT(*) FROM TestTable;", &[])?;
+/// #     let ds = ds.unwrap();
+/// #     let mut cur = ds.cursor()?;
+/// #     assert!(cur.advance()?);
+/// #     let rec = cur.current_record().unwrap();
+/// #     assert_eq!(rec.get_at(0)?.to_i64()?, 1);
+/// #
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [number of connection attempts]: #method.max_conn_attempts
+pub struct RemoteEngineBuilder<'a> {
+    runtime: &'a Runtime,
+    params: Params,
+}
+
+impl<'a> RemoteEngineBuilder<'a> {
+    /// Creates a new builder for connecting to the server at `host:port`.
+    ///
+    /// Other parameters are initialized with the default values.
+    pub fn new(runtime: &'a Runtime, host: &str, port: u16) -> Self {
+        RemoteEngineBuilder {
+            runtime,
+            params: Params::new(host, port),
+        }
+    }
+
+    /// Sets the server host name.
+    pub fn host(&mut self, host: &str) -> &mut Self {
+        self.params.host(host);
+        self
+    }
+
+    /// Sets the server port.
+    pub fn port(&mut self, port: u16) -> &mut Self {
+        self.params.port(port);
+        self
+    }
+
+    /// Sets the transmit buffer size.
+    ///
+    /// See [`Params::tx_buf_size()`] for details.
+    ///
+    /// [`Params::tx_buf_size()`]: ./struct.Params.html#method.tx_buf_size
+    pub fn tx_buf_size(&mut self, tx_buf_size: usize) -> &mut Self {
+        self.params.tx_buf_size(tx_buf_size);
+        self
+    }
+
+    /// Sets the maximum number of connection attempts.
+    ///
+    /// Default value is 10.
+    pub fn max_conn_attempts(&mut self, max_conn_attempts: u32) -> &mut Self {
+        self.params.max_conn_attempts(max_conn_attempts);
+        self
+    }
+
+    /// Connects to the server using the configured parameters.
+    pub fn build(&self) -> Result<RemoteEngine<'a>> {
+        RemoteEngine::connect_with(self.runtime, &self.params)
+    }
+}
+
 impl<'a> Drop for RemoteEngine<'a> {
     fn drop(&mut self) {
         unsafe {
@@ -228,4 +406,8 @@ impl<'a> Engine for RemoteEngine<'a> {
     fn get_engine(&self) -> exdb_sys::database_t {
         self.h
     }
+
+    fn last_statement_slot(&self) -> Option<&RefCell<Option<String>>> {
+        Some(&self.last_statement)
+    }
 }
@@ -4,17 +4,19 @@
 // Copyright (c) 2020 McObject LLC
 // All Rights Reserved
 
+use std::cell::RefCell;
 use std::marker::PhantomData;
 use std::mem::MaybeUninit;
 use std::ptr;
+use std::sync::atomic::{AtomicU64, Ordering};
 
 use crate::sql::allocator::{Owned, Ref};
 use crate::sql::data_source::DataSource;
-use crate::sql::engine::Engine;
-use crate::sql::result_from_code;
+use crate::sql::engine::{Engine, StatementKind};
 use crate::sql::trans::Transaction;
 use crate::sql::value::{ToValue, Value};
-use crate::{exdb_sys, Result};
+use crate::sql::{mcosql_error_code, result_from_code};
+use crate::{exdb_sys, Error, Result};
 
 pub(crate) struct Statement {}
 
@@ -25,7 +27,8 @@ impl Statement {
         values: &[&dyn ToValue],
     ) -> Result<i64> {
         let alloc = Owned::new()?;
-        let mut sql_values = Statement::create_values(Ref::new(&alloc), values)?;
+        let mut sql_values = Statement::create_values(Ref::new(&alloc), values)
+            .map_err(|e| ctx.statement_failed(sql, e))?;
         let mut n_records = MaybeUninit::uninit();
 
         result_from_code(unsafe {
@@ -38,7 +41,11 @@ impl Statement {
                 sql_values.len() as exdb_sys::size_t,
             )
         })
-        .and(Ok(unsafe { n_records.assume_init() }))
+        .map_err(|e| ctx.statement_failed(sql, e))?;
+
+        ctx.statement_executed(sql);
+
+        Ok(unsafe { n_records.assume_init() })
     }
 
     pub(crate) fn execute_query<'c>(
@@ -47,7 +54,8 @@ impl Statement {
         values: &[&dyn ToValue],
     ) -> Result<Option<DataSource<'c>>> {
         let alloc = Owned::new()?;
-        let mut sql_values = Statement::create_values(Ref::new(&alloc), values)?;
+        let mut sql_values = Statement::create_values(Ref::new(&alloc), values)
+            .map_err(|e| ctx.statement_failed(sql, e))?;
         let mut ds = MaybeUninit::uninit();
 
         result_from_code(unsafe {
@@ -59,15 +67,173 @@ impl Statement {
                 sql_values.as_mut_ptr() as *mut exdb_sys::mcosql_rs_value,
                 sql_values.len() as exdb_sys::size_t,
             )
-        })?;
+        })
+        .map_err(|e| ctx.statement_failed(sql, e))?;
+
+        ctx.statement_executed(sql);
 
         let ds = unsafe { ds.assume_init() };
 
         if ds.is_null() {
             Ok(None)
         } else {
-            Ok(Some(DataSource::new(ds, &())))
+            Ok(Some(DataSource::new(ds, ctx.schema_version)))
+        }
+    }
+
+    pub(crate) fn kind(sql: &str) -> Result<StatementKind> {
+        let (keyword, rest) = Statement::leading_keyword(sql);
+
+        if keyword.is_empty() {
+            return Err(Error::new_sql(mcosql_error_code::COMPILE_ERROR));
+        }
+
+        let keyword = if keyword.eq_ignore_ascii_case("WITH") {
+            Statement::with_body_keyword(rest)
+        } else {
+            keyword
+        };
+
+        match keyword.to_ascii_uppercase().as_str() {
+            "SELECT" => Ok(StatementKind::Query),
+            "INSERT" | "UPDATE" | "DELETE" | "TRUNCATE" => Ok(StatementKind::Dml),
+            "CREATE" | "DROP" | "ALTER" => Ok(StatementKind::Ddl),
+            _ => Ok(StatementKind::Other),
+        }
+    }
+
+    // Returns the first SELECT, INSERT, UPDATE, or DELETE keyword following
+    // the common table expressions of a WITH clause, or an empty string.
+    fn with_body_keyword(sql: &str) -> &str {
+        let b = sql.as_bytes();
+        let mut depth = 0usize;
+        let mut i = 0;
+
+        while i < b.len() {
+            match b[i] {
+                b'(' => depth += 1,
+                b')' => depth = depth.saturating_sub(1),
+                q @ b'\'' | q @ b'"' => {
+                    i += 1;
+                    while i < b.len() && b[i] != q {
+                        i += 1;
+                    }
+                }
+                b'-' if b.get(i + 1) == Some(&b'-') => {
+                    while i < b.len() && b[i] != b'\n' {
+                        i += 1;
+                    }
+                }
+                b'/' if b.get(i + 1) == Some(&b'*') => {
+                    i = sql[i + 2..]
+                        .find("*/")
+                        .map_or(b.len(), |pos| i + 2 + pos + 1);
+                }
+                c if c.is_ascii_alphabetic() => {
+                    let start = i;
+                    while i < b.len() && (b[i].is_ascii_alphanumeric() || b[i] == b'_') {
+                        i += 1;
+                    }
+
+                    let word = &sql[start..i];
+                    if depth == 0
+                        && ["SELECT", "INSERT", "UPDATE", "DELETE"]
+                            .iter()
+                            .any(|k| word.eq_ignore_ascii_case(k))
+                    {
+                        return word;
+                    }
+                    continue;
+                }
+                _ => {}
+            }
+            i += 1;
+        }
+
+        ""
+    }
+
+    // Skips the leading whitespace, comments, and opening parentheses, and
+    // returns the first word of the statement along with the rest of it.
+    fn leading_keyword(sql: &str) -> (&str, &str) {
+        let mut rest = sql;
+
+        loop {
+            rest = rest.trim_start_matches(|c: char| c.is_whitespace() || c == '(');
+
+            if rest.starts_with("--") {
+                rest = rest.find('\n').map_or("", |pos| &rest[pos..]);
+            } else if rest.starts_with("/*") {
+                rest = rest.find("*/").map_or("", |pos| &rest[pos + 2..]);
+            } else {
+                break;
+            }
+        }
+
+        let end = rest
+            .find(|c: char| !c.is_ascii_alphabetic())
+            .unwrap_or(rest.len());
+
+        rest.split_at(end)
+    }
+
+    // Replaces the named placeholders (:name) outside of the string literals,
+    // quoted identifiers, and comments with positional ones, and returns the
+    // rewritten statement along with the arguments in the positional order.
+    pub(crate) fn bind_named<'v>(
+        sql: &str,
+        args: &[(&str, &'v dyn ToValue)],
+    ) -> Result<(String, Vec<&'v dyn ToValue>)> {
+        let b = sql.as_bytes();
+        let mut out = String::with_capacity(sql.len());
+        let mut values = Vec::with_capacity(args.len());
+        let mut used = vec![false; args.len()];
+        let mut copied = 0;
+        let mut i = 0;
+
+        while i < b.len() {
+            match b[i] {
+                b'\'' | b'"' => {
+                    let q = b[i] as char;
+                    i = sql[i + 1..].find(q).map_or(b.len(), |p| i + p + 2);
+                }
+                b'-' if b.get(i + 1) == Some(&b'-') => {
+                    i = sql[i..].find('\n').map_or(b.len(), |p| i + p);
+                }
+                b'/' if b.get(i + 1) == Some(&b'*') => {
+                    i = sql[i + 2..].find("*/").map_or(b.len(), |p| i + p + 4);
+                }
+                b':' if (i == 0 || b[i - 1] != b':')
+                    && matches!(b.get(i + 1), Some(c) if c.is_ascii_alphabetic() || *c == b'_') =>
+                {
+                    let end = sql[i + 1..]
+                        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                        .map_or(b.len(), |p| i + p + 1);
+                    let name = &sql[i + 1..end];
+
+                    let idx = args
+                        .iter()
+                        .position(|(n, _)| n.strip_prefix(':').unwrap_or(n) == name)
+                        .ok_or(Error::new_sql(mcosql_error_code::COMPILE_ERROR))?;
+
+                    out.push_str(&sql[copied..i]);
+                    out.push('?');
+                    values.push(args[idx].1);
+                    used[idx] = true;
+
+                    i = end;
+                    copied = end;
+                }
+                _ => i += 1,
+            }
         }
+
+        if used.contains(&false) {
+            return Err(Error::new_sql(mcosql_error_code::COMPILE_ERROR));
+        }
+
+        out.push_str(&sql[copied..]);
+        Ok((out, values))
     }
 
     fn create_values<'a>(alloc: Ref<'a>, values: &[&dyn ToValue]) -> Result<Vec<Value<'a>>> {
@@ -83,6 +249,8 @@ pub(crate) struct ExecutionContext<'a> {
     owner: PhantomData<&'a ()>,
     engine: exdb_sys::database_t,
     transaction: exdb_sys::transaction_t,
+    last_statement: Option<&'a RefCell<Option<String>>>,
+    schema_version: Option<&'a AtomicU64>,
 }
 
 impl<'a> ExecutionContext<'a> {
@@ -91,6 +259,8 @@ impl<'a> ExecutionContext<'a> {
             owner: PhantomData,
             engine: engine.get_engine(),
             transaction: ptr::null_mut(),
+            last_statement: engine.last_statement_slot(),
+            schema_version: engine.schema_version_slot(),
         }
     }
 
@@ -99,6 +269,30 @@ impl<'a> ExecutionContext<'a> {
             owner: PhantomData,
             engine: transaction.engine.get_engine(),
             transaction: transaction.h,
+            last_statement: transaction.engine.last_statement_slot(),
+            schema_version: transaction.engine.schema_version_slot(),
+        }
+    }
+
+    // Records the text of the failed statement, and passes the error on.
+    fn statement_failed(&self, sql: &str, e: Error) -> Error {
+        if let Some(slot) = self.last_statement {
+            *slot.borrow_mut() = Some(sql.to_string());
+        }
+        e
+    }
+
+    // Clears the failed statement record, and bumps the schema version if
+    // the statement was a DDL statement.
+    fn statement_executed(&self, sql: &str) {
+        if let Some(slot) = self.last_statement {
+            slot.borrow_mut().take();
+        }
+
+        if let Some(version) = self.schema_version {
+            if let Ok(StatementKind::Ddl) = Statement::kind(sql) {
+                version.fetch_add(1, Ordering::AcqRel);
+            }
         }
     }
 }
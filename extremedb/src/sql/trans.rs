@@ -16,7 +16,14 @@
 //! if needed; dropping the transaction object causes the transaction to be
 //! rolled back implicitly.
 //!
+//! Alternatively, a transaction can be started using
+//! [`LocalEngine::begin_transaction()`], which returns a
+//! [`TransactionGuard`]. The guarded transaction is committed implicitly
+//! when the guard is dropped, unless it is rolled back.
+//!
 //! [`Transaction`]: ./struct.Transaction.html
+//! [`LocalEngine::begin_transaction()`]: ../engine/struct.LocalEngine.html#method.begin_transaction
+//! [`TransactionGuard`]: ./struct.TransactionGuard.html
 //!
 //! # Examples
 //!
@@ -50,7 +57,10 @@
 //! ```
 
 use std::mem::{self, MaybeUninit};
+use std::ops::Deref;
 use std::ptr;
+use std::thread;
+use std::time::Duration;
 
 use crate::sql::data_source::DataSource;
 use crate::sql::engine::{Engine, LocalEngine};
@@ -74,6 +84,83 @@ pub enum Mode {
     Exclusive = exdb_sys::mcosql_transaction_mode::TM_EXCLUSIVE as isize,
 }
 
+/// Transaction options.
+///
+/// This structure bundles the parameters of a transaction started by
+/// [`LocalEngine::transaction_with()`]. By default, the transaction is started
+/// in the `ReadWrite` mode with priority `0`, and its elapsed time is not
+/// limited.
+///
+/// The commit policy cannot be set for individual SQL transactions; it is
+/// configured for the whole database using the [`LogParams`].
+///
+/// # Examples
+///
+/// ```
+/// # use extremedb::sql::trans::{Mode, TxOptions};
+/// # use std::time::Duration;
+/// let mut opts = TxOptions::new();
+/// opts.mode(Mode::ReadOnly)
+///     .priority(1)
+///     .max_elapsed(Duration::from_secs(1));
+/// ```
+///
+/// [`LocalEngine::transaction_with()`]: ../engine/struct.LocalEngine.html#method.transaction_with
+/// [`LogParams`]: ../../database/struct.LogParams.html
+pub struct TxOptions {
+    pub(crate) mode: Mode,
+    pub(crate) priority: i32,
+    pub(crate) max_elapsed: Option<Duration>,
+}
+
+impl TxOptions {
+    /// Creates a new options structure initialized with default values.
+    pub fn new() -> Self {
+        TxOptions {
+            mode: Mode::ReadWrite,
+            priority: 0,
+            max_elapsed: None,
+        }
+    }
+
+    /// Sets the transaction mode.
+    pub fn mode(&mut self, mode: Mode) -> &mut Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Sets the transaction priority.
+    ///
+    /// Refer to [`Transaction::begin()`] for the details.
+    ///
+    /// [`Transaction::begin()`]: ./struct.Transaction.html#method.begin
+    pub fn priority(&mut self, priority: i32) -> &mut Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Sets the maximum elapsed time of the transaction.
+    ///
+    /// This is a check performed after the transaction's work is done: if
+    /// the transaction took longer, it is rolled back instead of being
+    /// committed, and the `MCO_E_LONG_TRANSACTION` error is returned. The
+    /// running transaction is not interrupted when the time is exceeded.
+    ///
+    /// This limit is unrelated to the database's `max_trans_time`
+    /// parameter, which is only supported by custom-built runtimes and
+    /// cannot be set for individual transactions.
+    pub fn max_elapsed(&mut self, max_elapsed: Duration) -> &mut Self {
+        self.max_elapsed = Some(max_elapsed);
+        self
+    }
+}
+
+impl Default for TxOptions {
+    fn default() -> Self {
+        TxOptions::new()
+    }
+}
+
 /// A transaction.
 ///
 /// This type allows for explicit transaction control when using the local
@@ -88,6 +175,16 @@ pub struct Transaction<'a> {
 
 impl<'a> Transaction<'a> {
     /// Starts a new transaction.
+    ///
+    /// `priority` is the transaction priority. *e*X*treme*DB assigns
+    /// priorities to individual transactions rather than connections; to
+    /// favor a specific connection, start its transactions with a higher
+    /// priority. The conventional values range from `-2` (idle) through `0`
+    /// (foreground, the default) to `1` (high). Transactions with the same
+    /// priority are scheduled according to the database's
+    /// [`TransSchedPolicy`].
+    ///
+    /// [`TransSchedPolicy`]: ../../database/enum.TransSchedPolicy.html
     pub fn begin(engine: &'a LocalEngine, mode: Mode, priority: i32) -> Result<Transaction<'a>> {
         let mut h = MaybeUninit::uninit();
 
@@ -161,3 +258,65 @@ impl<'a> Drop for Transaction<'a> {
         }
     }
 }
+
+/// A transaction which is committed when dropped.
+///
+/// The guard is returned by [`LocalEngine::begin_transaction()`], and
+/// dereferences to the guarded [`Transaction`], which can be used to execute
+/// statements and queries.
+///
+/// Unlike a plain transaction, the guarded transaction is committed
+/// implicitly when the guard goes out of scope, unless it is rolled back
+/// using [`rollback()`]. The errors which occur during the implicit commit
+/// cannot be reported; [`commit()`] can be called to commit the transaction
+/// explicitly and receive the result. If the guard is dropped while the
+/// thread is panicking, the transaction is rolled back instead.
+///
+/// [`LocalEngine::begin_transaction()`]: ../engine/struct.LocalEngine.html#method.begin_transaction
+/// [`Transaction`]: ./struct.Transaction.html
+/// [`rollback()`]: #method.rollback
+/// [`commit()`]: #method.commit
+pub struct TransactionGuard<'a> {
+    txn: Option<Transaction<'a>>,
+}
+
+impl<'a> TransactionGuard<'a> {
+    pub(crate) fn new(txn: Transaction<'a>) -> Self {
+        TransactionGuard { txn: Some(txn) }
+    }
+
+    /// Commits the transaction.
+    pub fn commit(mut self) -> Result<()> {
+        self.take().commit()
+    }
+
+    /// Rolls back the transaction.
+    pub fn rollback(mut self) -> Result<()> {
+        self.take().rollback()
+    }
+
+    fn take(&mut self) -> Transaction<'a> {
+        // The transaction is only taken by the consuming methods.
+        self.txn.take().unwrap()
+    }
+}
+
+impl<'a> Deref for TransactionGuard<'a> {
+    type Target = Transaction<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        self.txn.as_ref().unwrap()
+    }
+}
+
+impl<'a> Drop for TransactionGuard<'a> {
+    fn drop(&mut self) {
+        if let Some(txn) = self.txn.take() {
+            if thread::panicking() {
+                let _ = txn.rollback();
+            } else {
+                let _ = txn.commit();
+            }
+        }
+    }
+}
@@ -198,6 +198,10 @@
 //! Strings and binary values can be passed as string slices and [`Binary`],
 //! respectively. Note that it is necessary to use the `Binary` helper type
 //! instead of `u8` slices: the latter are converted to [`Array`] instead.
+//! `Binary` can wrap any borrowed byte container, such as a slice or a
+//! `Vec<u8>`. Byte vectors, byte arrays and byte string literals (such as
+//! `b"data"`) do not implement [`ToValue`], and must always be wrapped in
+//! `Binary`.
 //!
 //! ```
 //! # use extremedb::connection::Connection;
@@ -232,7 +236,10 @@
 //! ## Arrays, Sequences, and Blobs
 //!
 //! Arrays, as well as sequences and blobs, are passed as slices of the
-//! appropriate types:
+//! appropriate types. Fixed-size Rust arrays and vectors of any element type
+//! except `u8` can be passed as arrays as well. The elements of the
+//! non-plain types, such as strings, are allocated individually by the SQL
+//! array's allocator.
 //!
 //! ```
 //! # use extremedb::connection::Connection;
@@ -262,6 +269,17 @@
 //!         "INSERT INTO TestTable(a, b) VALUES(?, ?);",
 //!         &[&array, &blob],
 //!     )?;
+//!
+//!     engine.execute_statement("CREATE TABLE Strings(s array(string));", &[])?;
+//!     engine.execute_statement("INSERT INTO Strings(s) VALUES(?);", &[&["a", "b", "c"]])?;
+//!
+//!     let ds = engine.execute_query("SELECT s FROM Strings;", &[])?.unwrap();
+//!     let mut cur = ds.cursor()?;
+//!     assert!(cur.advance()?);
+//!
+//!     let rec = cur.current_record().unwrap();
+//!     let s = rec.get_at(0)?;
+//!     assert_eq!(s.as_array()?.to_vec::<String>()?, vec!["a", "b", "c"]);
 //! #     Ok(())
 //! # }
 //! ```
@@ -528,12 +546,17 @@
 //! [`Blob`]: ./struct.Blob.html
 //!
 
+use std::borrow::Cow;
+use std::cmp::Ordering;
 use std::convert::TryFrom;
 use std::ffi::c_void;
 use std::fmt::{Display, Error as FmtError, Formatter};
+use std::hash::{Hash, Hasher};
+use std::io;
 use std::marker::PhantomData;
 use std::mem::MaybeUninit;
-use std::ops::Deref;
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::ops::{Add, Deref, Neg, Sub};
 use std::ptr;
 use std::slice;
 use std::str;
@@ -546,8 +569,21 @@ use crate::{exdb_sys, Error, Result};
 
 use exdb_sys::mcosql_column_type;
 
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Utc};
+
+#[cfg(feature = "time")]
+use time::OffsetDateTime;
+
+#[cfg(feature = "rust_decimal")]
+use rust_decimal::Decimal;
+
 /// The type of a generic SQL value.
+///
+/// New variants may be added as the SQL engine's types become supported, so
+/// matches on this type must include a wildcard arm.
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[non_exhaustive]
 pub enum Type {
     /// A `null` value.
     Null = mcosql_column_type::CT_NULL as isize,
@@ -606,14 +642,126 @@ pub enum Type {
     /// A blob.
     Blob = mcosql_column_type::CT_BLOB as isize,
 
-    // DataSource = mcosql_column_type::CT_DATA_SOURCE as isize,
+    /// A nested data source.
+    ///
+    /// Values of this type can be produced by subqueries and some of the SQL
+    /// engine functions.
+    ///
+    /// Nested data source values are only classified: they cannot be
+    /// converted into a [`DataSource`] (see the [SQL API limitations]), or
+    /// to any other type.
+    ///
+    /// [`DataSource`]: ../data_source/struct.DataSource.html
+    /// [SQL API limitations]: ../index.html#sql-api-limitations
+    DataSource = mcosql_column_type::CT_DATA_SOURCE as isize,
+
+    /// A list of values.
+    ///
+    /// Lists are produced by the SQL engine internally (for example, as the
+    /// right-hand side of the `IN` operator). Unlike [`Array`]s, they cannot
+    /// be stored in tables, and unlike [`Sequence`]s, they are not backed
+    /// by the database storage.
+    ///
+    /// List values are only classified: their elements cannot be accessed
+    /// (see the [SQL API limitations]), and converting them to any other
+    /// type fails.
+    ///
+    /// [`Array`]: ./struct.Array.html
+    /// [SQL API limitations]: ../index.html#sql-api-limitations
+    /// [`Sequence`]: ./struct.Sequence.html
+    List = mcosql_column_type::CT_LIST as isize,
 
-    // List = mcosql_column_type::CT_LIST as isize,
     /// A sequence.
     Sequence = mcosql_column_type::CT_SEQUENCE as isize,
 }
 
 impl Type {
+    /// Returns the *e*X*treme*DB SQL keyword for the type.
+    ///
+    /// The returned keyword can be used to declare the table columns. The
+    /// `Null`, `DataSource`, and `List` types are produced by the SQL engine
+    /// only and cannot be used in the column declarations; the `Array` and
+    /// `Sequence` column declarations require the element type as well.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use extremedb::sql::value::Type;
+    /// assert_eq!(Type::Int4.sql_name(), "integer");
+    /// assert_eq!(Type::String.sql_name(), "varchar");
+    /// assert_eq!(Type::Time.sql_name(), "timestamp");
+    /// ```
+    ///
+    /// The keywords are accepted in the `CREATE TABLE` statements:
+    ///
+    /// ```
+    /// # use extremedb::sql::engine::Engine;
+    /// # use extremedb::sql::value::Type;
+    /// # use extremedb::{connection, database, device, runtime, sql};
+    /// # use extremedb::device::util;
+    /// # fn main() -> extremedb::Result<()> {
+    /// #     let runtime = runtime::Runtime::start(vec![]);
+    /// #     let mut db_params = database::Params::new();
+    /// #     db_params
+    /// #         .ddl_dict_size(32768)
+    /// #         .max_classes(100)
+    /// #         .max_indexes(1000);
+    /// #     let mut devs = util::DeviceContainer::new();
+    /// #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+    /// #     let conn = connection::Connection::new(&db)?;
+    /// #     let engine = sql::engine::LocalEngine::new(&conn)?;
+    ///     let types = [
+    ///         Type::Bool,
+    ///         Type::Int1,
+    ///         Type::Int2,
+    ///         Type::Int4,
+    ///         Type::Int8,
+    ///         Type::UInt1,
+    ///         Type::UInt2,
+    ///         Type::UInt4,
+    ///         Type::UInt8,
+    ///         Type::Real4,
+    ///         Type::Real8,
+    ///         Type::Time,
+    ///         Type::Numeric,
+    ///         Type::String,
+    ///         Type::Binary,
+    ///         Type::Blob,
+    ///     ];
+    ///
+    ///     for (i, ty) in types.iter().enumerate() {
+    ///         let ddl = format!("CREATE TABLE T{}(c {});", i, ty.sql_name());
+    ///         engine.execute_statement(&ddl, &[])?;
+    ///     }
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn sql_name(&self) -> &'static str {
+        match self {
+            Type::Null => "null",
+            Type::Bool => "boolean",
+            Type::Int1 => "tinyint",
+            Type::Int2 => "smallint",
+            Type::Int4 => "integer",
+            Type::Int8 => "bigint",
+            Type::UInt1 => "unsigned(1)",
+            Type::UInt2 => "unsigned(2)",
+            Type::UInt4 => "unsigned(4)",
+            Type::UInt8 => "unsigned(8)",
+            Type::Real4 => "real",
+            Type::Real8 => "double",
+            Type::Time => "timestamp",
+            Type::Numeric => "numeric",
+            Type::String => "varchar",
+            Type::Binary => "varbinary",
+            Type::Array => "array",
+            Type::Blob => "blob",
+            Type::DataSource => "datasource",
+            Type::List => "list",
+            Type::Sequence => "sequence",
+        }
+    }
+
     pub(crate) fn from_mco(v: mcosql_column_type::Type) -> Option<Self> {
         match v {
             mcosql_column_type::CT_NULL => Some(Type::Null),
@@ -634,6 +782,8 @@ impl Type {
             mcosql_column_type::CT_BINARY => Some(Type::Binary),
             mcosql_column_type::CT_ARRAY => Some(Type::Array),
             mcosql_column_type::CT_BLOB => Some(Type::Blob),
+            mcosql_column_type::CT_DATA_SOURCE => Some(Type::DataSource),
+            mcosql_column_type::CT_LIST => Some(Type::List),
             mcosql_column_type::CT_SEQUENCE => Some(Type::Sequence),
             _ => None,
         }
@@ -753,11 +903,11 @@ impl<'a> Value<'a> {
             .duration_since(UNIX_EPOCH)
             .or(Err(Error::new_sql(mcosql_error_code::INVALID_TYPE_CAST)))?;
 
-        let prec = unsafe {
-            exdb_sys::mco_runtime_getoption(
-                options::mco_rt_defines::keys::MCO_RT_OPTION_DATETIME_PRECISION as i32,
-            )
-        } as u128;
+        Value::new_duration(&dur, alloc)
+    }
+
+    fn new_duration(dur: &Duration, alloc: AllocatorRef<'a>) -> Result<Self> {
+        let prec = Value::datetime_precision() as u128;
 
         let val;
 
@@ -774,9 +924,13 @@ impl<'a> Value<'a> {
         let val =
             u64::try_from(val).or(Err(Error::new_sql(mcosql_error_code::INVALID_TYPE_CAST)))?;
 
+        Value::new_date_time_ticks(val, alloc)
+    }
+
+    fn new_date_time_ticks(ticks: u64, alloc: AllocatorRef<'a>) -> Result<Self> {
         let mut h = MaybeUninit::uninit();
         result_from_code(unsafe {
-            exdb_sys::mcosql_rs_value_create_datetime(alloc.h, val, h.as_mut_ptr())
+            exdb_sys::mcosql_rs_value_create_datetime(alloc.h, ticks, h.as_mut_ptr())
         })
         .and(Ok(Value {
             alloc: PhantomData,
@@ -814,6 +968,7 @@ impl<'a> Value<'a> {
     /// Depending on the contained type, returns:
     ///
     /// - `String` and `Binary`: length of the string or binary string;
+    /// - `Blob`: total size of the blob data;
     /// - `Array`: number of elements.
     pub fn size(&self) -> Result<usize> {
         let mut ret = MaybeUninit::uninit();
@@ -821,6 +976,58 @@ impl<'a> Value<'a> {
             .and(Ok(unsafe { ret.assume_init() } as usize))
     }
 
+    /// Returns the length of the contents of a `String`, `Binary`, or
+    /// `Array` value.
+    ///
+    /// For strings and binaries, the length in bytes is returned; for arrays,
+    /// the number of elements. Unlike [`to_string()`] or [`to_binary()`],
+    /// this method does not copy the data, so it can be used to check the
+    /// size of large values before fetching them.
+    ///
+    /// Returns an error if the value has any other type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use extremedb::sql::engine::Engine;
+    /// # use extremedb::{connection, database, device, runtime, sql};
+    /// # use extremedb::device::util;
+    /// # fn main() -> extremedb::Result<()> {
+    /// #     let runtime = runtime::Runtime::start(vec![]);
+    /// #     let mut db_params = database::Params::new();
+    /// #     db_params
+    /// #         .ddl_dict_size(32768)
+    /// #         .max_classes(100)
+    /// #         .max_indexes(1000);
+    /// #     let mut devs = util::DeviceContainer::new();
+    /// #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+    /// #     let conn = connection::Connection::new(&db)?;
+    /// #     let engine = sql::engine::LocalEngine::new(&conn)?;
+    ///     engine.execute_statement("CREATE TABLE TestTable(i integer, s string);", &[])?;
+    ///
+    ///     let s = "x".repeat(100_000);
+    ///     engine.execute_statement("INSERT INTO TestTable(i, s) VALUES(?, ?);", &[&1, &s.as_str()])?;
+    ///
+    ///     let ds = engine.execute_query("SELECT i, s FROM TestTable;", &[])?.unwrap();
+    ///     let mut cur = ds.cursor()?;
+    ///     assert!(cur.advance()?);
+    ///
+    ///     let rec = cur.current_record().unwrap();
+    ///     assert_eq!(rec.get_at(1)?.content_len()?, 100_000);
+    ///     assert!(rec.get_at(0)?.content_len().is_err());
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`to_string()`]: #method.to_string
+    /// [`to_binary()`]: #method.to_binary
+    pub fn content_len(&self) -> Result<usize> {
+        match self.value_type()? {
+            Type::String | Type::Binary | Type::Array => self.size(),
+            _ => Err(Error::new_sql(mcosql_error_code::INVALID_TYPE_CAST)),
+        }
+    }
+
     /// Returns `true` if the value is an SQL `null` value.
     pub fn is_null(&self) -> bool {
         0 != unsafe { exdb_sys::mcosql_rs_value_is_null(self.h) }
@@ -832,6 +1039,86 @@ impl<'a> Value<'a> {
         0 != unsafe { exdb_sys::mcosql_rs_value_is_true(self.h) }
     }
 
+    /// Casts the value to `bool`.
+    ///
+    /// Integers are `true` if non-zero; strings are converted if they
+    /// contain `true` or `false` (case-insensitive). Other values cause an
+    /// `INVALID_TYPE_CAST` error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use extremedb::sql::engine::Engine;
+    /// # use extremedb::{connection, database, runtime, sql};
+    /// # use extremedb::device::util;
+    /// # fn main() -> extremedb::Result<()> {
+    /// #     let runtime = runtime::Runtime::start(vec![]);
+    /// #     let mut db_params = database::Params::new();
+    /// #     db_params
+    /// #         .ddl_dict_size(32768)
+    /// #         .max_classes(100)
+    /// #         .max_indexes(1000);
+    /// #     let mut devs = util::DeviceContainer::new();
+    /// #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+    /// #     let conn = connection::Connection::new(&db)?;
+    /// #     let engine = sql::engine::LocalEngine::new(&conn)?;
+    ///     engine.execute_statement(
+    ///         "CREATE TABLE TestTable(k integer, b boolean, i integer, s string);",
+    ///         &[],
+    ///     )?;
+    ///     engine.execute_statement(
+    ///         "INSERT INTO TestTable(k, b, i, s) VALUES(?, ?, ?, ?);",
+    ///         &[&1, &true, &0, &"False"],
+    ///     )?;
+    ///     engine.execute_statement(
+    ///         "INSERT INTO TestTable(k, b, i, s) VALUES(?, ?, ?, ?);",
+    ///         &[&2, &false, &1, &"maybe"],
+    ///     )?;
+    ///
+    ///     let ds = engine
+    ///         .execute_query("SELECT b, i, s FROM TestTable ORDER BY k;", &[])?
+    ///         .unwrap();
+    ///     let mut cur = ds.cursor()?;
+    ///
+    ///     assert!(cur.advance()?);
+    ///     let rec = cur.current_record().unwrap();
+    ///     assert!(rec.get_at(0)?.to_bool()?);
+    ///     assert!(!rec.get_at(1)?.to_bool()?);
+    ///     assert!(!rec.get_at(2)?.to_bool()?);
+    ///
+    ///     assert!(cur.advance()?);
+    ///     let rec = cur.current_record().unwrap();
+    ///     assert!(!rec.get_at(0)?.to_bool()?);
+    ///     assert!(rec.get_at(1)?.to_bool()?);
+    ///     assert!(rec.get_at(2)?.to_bool().is_err());
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn to_bool(&self) -> Result<bool> {
+        match self.value_type()? {
+            Type::Bool => Ok(self.is_true()),
+            Type::Int1
+            | Type::Int2
+            | Type::Int4
+            | Type::Int8
+            | Type::UInt1
+            | Type::UInt2
+            | Type::UInt4
+            | Type::UInt8 => self.to_i64().map(|v| v != 0),
+            Type::String => {
+                let s = self.as_str()?;
+                if s.eq_ignore_ascii_case("true") {
+                    Ok(true)
+                } else if s.eq_ignore_ascii_case("false") {
+                    Ok(false)
+                } else {
+                    Err(Error::new_sql(mcosql_error_code::INVALID_TYPE_CAST))
+                }
+            }
+            _ => Err(Error::new_sql(mcosql_error_code::INVALID_TYPE_CAST)),
+        }
+    }
+
     /// Casts the value to `i64`.
     ///
     /// Strings are parsed and converted, if possible; otherwise an error is
@@ -842,6 +1129,125 @@ impl<'a> Value<'a> {
             .and(Ok(unsafe { val.assume_init() }))
     }
 
+    /// Casts the value to `u64`.
+    ///
+    /// The SQL engine stores all integers as 64-bit signed values. For the
+    /// unsigned types, this method reinterprets the stored value, so that
+    /// the values above `i64::MAX` are returned correctly. Negative values
+    /// of the other types cause an `INVALID_TYPE_CAST` error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use extremedb::sql::engine::Engine;
+    /// # use extremedb::{connection, database, runtime, sql};
+    /// # use extremedb::device::util;
+    /// # fn main() -> extremedb::Result<()> {
+    /// #     let runtime = runtime::Runtime::start(vec![]);
+    /// #     let mut db_params = database::Params::new();
+    /// #     db_params
+    /// #         .ddl_dict_size(32768)
+    /// #         .max_classes(100)
+    /// #         .max_indexes(1000);
+    /// #     let mut devs = util::DeviceContainer::new();
+    /// #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+    /// #     let conn = connection::Connection::new(&db)?;
+    /// #     let engine = sql::engine::LocalEngine::new(&conn)?;
+    ///     engine.execute_statement("CREATE TABLE TestTable(u unsigned(8), i bigint);", &[])?;
+    ///     engine.execute_statement(
+    ///         "INSERT INTO TestTable(u, i) VALUES(?, ?);",
+    ///         &[&u64::MAX, &-1i64],
+    ///     )?;
+    ///
+    ///     let ds = engine.execute_query("SELECT u, i FROM TestTable;", &[])?.unwrap();
+    ///     let mut cur = ds.cursor()?;
+    ///     assert!(cur.advance()?);
+    ///
+    ///     let rec = cur.current_record().unwrap();
+    ///     assert_eq!(rec.get_at(0)?.to_u64()?, u64::MAX);
+    ///     assert!(rec.get_at(1)?.to_u64().is_err());
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn to_u64(&self) -> Result<u64> {
+        match self.value_type()? {
+            Type::UInt1 | Type::UInt2 | Type::UInt4 | Type::UInt8 => {
+                self.to_i64().map(|v| v as u64)
+            }
+            _ => u64::try_from(self.to_i64()?)
+                .or(Err(Error::new_sql(mcosql_error_code::INVALID_TYPE_CAST))),
+        }
+    }
+
+    /// Casts the value to an enum type, using the integer value as the
+    /// discriminant.
+    ///
+    /// The value is read using [`to_i64()`] and converted using the target
+    /// type's `TryFrom<i64>` implementation. If the conversion fails, an
+    /// `INVALID_TYPE_CAST` error is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use extremedb::sql::engine::Engine;
+    /// # use extremedb::{connection, database, runtime, sql};
+    /// # use extremedb::device::util;
+    /// # use std::convert::TryFrom;
+    /// #[derive(Debug, PartialEq)]
+    /// #[repr(i64)]
+    /// enum Color {
+    ///     Red = 0,
+    ///     Green = 1,
+    ///     Blue = 2,
+    /// }
+    ///
+    /// impl TryFrom<i64> for Color {
+    ///     type Error = ();
+    ///
+    ///     fn try_from(v: i64) -> Result<Self, ()> {
+    ///         match v {
+    ///             0 => Ok(Color::Red),
+    ///             1 => Ok(Color::Green),
+    ///             2 => Ok(Color::Blue),
+    ///             _ => Err(()),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// # fn main() -> extremedb::Result<()> {
+    /// #     let runtime = runtime::Runtime::start(vec![]);
+    /// #     let mut db_params = database::Params::new();
+    /// #     db_params
+    /// #         .ddl_dict_size(32768)
+    /// #         .max_classes(100)
+    /// #         .max_indexes(1000);
+    /// #     let mut devs = util::DeviceContainer::new();
+    /// #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+    /// #     let conn = connection::Connection::new(&db)?;
+    /// #     let engine = sql::engine::LocalEngine::new(&conn)?;
+    ///     engine.execute_statement("CREATE TABLE TestTable(c integer);", &[])?;
+    ///     engine.execute_statement("INSERT INTO TestTable(c) VALUES(?);", &[&2])?;
+    ///     engine.execute_statement("INSERT INTO TestTable(c) VALUES(?);", &[&5])?;
+    ///
+    ///     let ds = engine.execute_query("SELECT c FROM TestTable ORDER BY c;", &[])?.unwrap();
+    ///     let mut cur = ds.cursor()?;
+    ///
+    ///     assert!(cur.advance()?);
+    ///     let rec = cur.current_record().unwrap();
+    ///     assert_eq!(rec.get_at(0)?.to_enum::<Color>()?, Color::Blue);
+    ///
+    ///     assert!(cur.advance()?);
+    ///     let rec = cur.current_record().unwrap();
+    ///     assert!(rec.get_at(0)?.to_enum::<Color>().is_err());
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`to_i64()`]: #method.to_i64
+    pub fn to_enum<T: TryFrom<i64>>(&self) -> Result<T> {
+        T::try_from(self.to_i64()?).or(Err(Error::new_sql(mcosql_error_code::INVALID_TYPE_CAST)))
+    }
+
     /// Casts the value to `f64`.
     ///
     /// Strings are parsed and converted, if possible; otherwise an error is
@@ -867,12 +1273,115 @@ impl<'a> Value<'a> {
     /// the beginning of the epoch, and converts the resulting value to
     /// `std::time::SystemTime`.
     pub fn to_system_time(&self) -> Result<SystemTime> {
-        let prec = unsafe {
+        Value::ticks_to_system_time(self.to_date_time()?, Value::datetime_precision())
+    }
+
+    /// Casts the value to the number of system ticks elapsed since
+    /// the beginning of the epoch, and converts the resulting value to
+    /// `chrono::DateTime<Utc>`.
+    ///
+    /// The conversion follows the same rules as [`to_system_time()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use extremedb::sql::engine::Engine;
+    /// # use extremedb::{connection, database, runtime, sql};
+    /// # use extremedb::device::util;
+    /// # use chrono::{TimeZone, Utc};
+    /// # fn main() -> extremedb::Result<()> {
+    /// #     let runtime = runtime::Runtime::start(vec![]);
+    /// #     let mut db_params = database::Params::new();
+    /// #     db_params
+    /// #         .ddl_dict_size(32768)
+    /// #         .max_classes(100)
+    /// #         .max_indexes(1000);
+    /// #     let mut devs = util::DeviceContainer::new();
+    /// #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+    /// #     let conn = connection::Connection::new(&db)?;
+    /// #     let engine = sql::engine::LocalEngine::new(&conn)?;
+    ///     let ts = Utc.with_ymd_and_hms(2020, 9, 13, 12, 26, 40).unwrap();
+    ///
+    ///     engine.execute_statement("CREATE TABLE TestTable(ts timestamp);", &[])?;
+    ///     engine.execute_statement("INSERT INTO TestTable(ts) VALUES(?);", &[&ts])?;
+    ///
+    ///     let ds = engine.execute_query("SELECT ts FROM TestTable;", &[])?.unwrap();
+    ///     let mut cur = ds.cursor()?;
+    ///     assert!(cur.advance()?);
+    ///     let rec = cur.current_record().unwrap();
+    ///     assert_eq!(rec.get_at(0)?.to_chrono_utc()?, ts);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`to_system_time()`]: #method.to_system_time
+    #[cfg(feature = "chrono")]
+    pub fn to_chrono_utc(&self) -> Result<DateTime<Utc>> {
+        self.to_system_time().map(DateTime::<Utc>::from)
+    }
+
+    /// Casts the value to the number of system ticks elapsed since
+    /// the beginning of the epoch, and converts the resulting value to
+    /// `time::OffsetDateTime` in UTC.
+    ///
+    /// The conversion follows the same rules as [`to_system_time()`].
+    ///
+    /// # Examples
+    ///
+    /// Timestamps with millisecond resolution:
+    ///
+    /// ```
+    /// # use extremedb::runtime::options::{DateTimePrecision, Opt};
+    /// # use extremedb::sql::engine::Engine;
+    /// # use extremedb::{connection, database, runtime, sql};
+    /// # use extremedb::device::util;
+    /// # use time::{Duration, OffsetDateTime};
+    /// # fn main() -> extremedb::Result<()> {
+    ///     let runtime = runtime::Runtime::start(vec![Opt::DateTimePrecision(
+    ///         DateTimePrecision::milliseconds(1),
+    ///     )]);
+    /// #     let mut db_params = database::Params::new();
+    /// #     db_params
+    /// #         .ddl_dict_size(32768)
+    /// #         .max_classes(100)
+    /// #         .max_indexes(1000);
+    /// #     let mut devs = util::DeviceContainer::new();
+    /// #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+    /// #     let conn = connection::Connection::new(&db)?;
+    /// #     let engine = sql::engine::LocalEngine::new(&conn)?;
+    ///     let ts = OffsetDateTime::from_unix_timestamp(1_600_000_000).unwrap()
+    ///         + Duration::milliseconds(250);
+    ///
+    ///     engine.execute_statement("CREATE TABLE TestTable(ts timestamp);", &[])?;
+    ///     engine.execute_statement("INSERT INTO TestTable(ts) VALUES(?);", &[&ts])?;
+    ///
+    ///     let ds = engine.execute_query("SELECT ts FROM TestTable;", &[])?.unwrap();
+    ///     let mut cur = ds.cursor()?;
+    ///     assert!(cur.advance()?);
+    ///     let rec = cur.current_record().unwrap();
+    ///
+    ///     let read = rec.get_at(0)?.to_offset_date_time()?;
+    ///     assert_eq!(read, ts);
+    ///     assert_eq!(read.millisecond(), 250);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`to_system_time()`]: #method.to_system_time
+    #[cfg(feature = "time")]
+    pub fn to_offset_date_time(&self) -> Result<OffsetDateTime> {
+        self.to_system_time().map(OffsetDateTime::from)
+    }
+
+    fn datetime_precision() -> u64 {
+        (unsafe {
             exdb_sys::mco_runtime_getoption(
                 options::mco_rt_defines::keys::MCO_RT_OPTION_DATETIME_PRECISION as i32,
             )
-        } as u64;
-        let dt = self.to_date_time()?;
+        }) as u64
+    }
+
+    fn ticks_to_system_time(dt: u64, prec: u64) -> Result<SystemTime> {
         let dur;
         if prec >= 1_000_000_000 {
             dur = Duration::from_nanos(dt / (prec / 1_000_000_000));
@@ -906,6 +1415,56 @@ impl<'a> Value<'a> {
         }
     }
 
+    /// Returns the contained fixed-width numeric value in minor units
+    /// (see [`Numeric::as_minor_units()`]), or an error if the value is not
+    /// a numeric.
+    ///
+    /// The result depends on the precision declared for the column: a
+    /// value of `12.34` is returned as `1234` for a `numeric(10, 2)` column,
+    /// but as `12340` for a `numeric(10, 3)` column.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use extremedb::sql::engine::Engine;
+    /// # use extremedb::sql::value::Numeric;
+    /// # use extremedb::{connection, database, runtime, sql};
+    /// # use extremedb::device::util;
+    /// # fn main() -> extremedb::Result<()> {
+    /// #     let runtime = runtime::Runtime::start(vec![]);
+    /// #     let mut db_params = database::Params::new();
+    /// #     db_params
+    /// #         .ddl_dict_size(32768)
+    /// #         .max_classes(100)
+    /// #         .max_indexes(1000);
+    /// #     let mut devs = util::DeviceContainer::new();
+    /// #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+    /// #     let conn = connection::Connection::new(&db)?;
+    /// #     let engine = sql::engine::LocalEngine::new(&conn)?;
+    ///     engine.execute_statement("CREATE TABLE Payment(amount numeric(10, 2));", &[])?;
+    ///     engine.execute_statement(
+    ///         "INSERT INTO Payment(amount) VALUES(?);",
+    ///         &[&Numeric::new(1999, 2).unwrap()],
+    ///     )?;
+    ///     engine.execute_statement(
+    ///         "INSERT INTO Payment(amount) VALUES(?);",
+    ///         &[&Numeric::new(501, 2).unwrap()],
+    ///     )?;
+    ///
+    ///     let total: i64 = engine.query_fold("SELECT amount FROM Payment;", &[], 0, |acc, rec| {
+    ///         Ok(acc + rec.get_at(0)?.to_minor_units()?)
+    ///     })?;
+    ///
+    ///     assert_eq!(total, 2500);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Numeric::as_minor_units()`]: ./struct.Numeric.html#method.as_minor_units
+    pub fn to_minor_units(&self) -> Result<i64> {
+        self.to_numeric().map(|num| num.as_minor_units())
+    }
+
     /// Converts the value to the string representation.
     pub fn to_string(&self) -> Result<String> {
         let alloc = allocator::Owned::new()?;
@@ -922,12 +1481,139 @@ impl<'a> Value<'a> {
 
     /// Returns a string slice pointing to the contents of a `String` value,
     /// or an error if the value is not a `String`.
+    ///
+    /// Also returns an `INVALID_TYPE_CAST` error if the string is not valid
+    /// UTF-8; use [`as_str_lossy()`] or [`string_bytes()`] to access such
+    /// strings.
+    ///
+    /// [`as_str_lossy()`]: #method.as_str_lossy
+    /// [`string_bytes()`]: #method.string_bytes
     pub fn as_str(&self) -> Result<&str> {
+        str::from_utf8(self.string_bytes()?)
+            .or(Err(Error::new_sql(mcosql_error_code::INVALID_TYPE_CAST)))
+    }
+
+    /// Returns the contents of a `String` value, replacing any invalid UTF-8
+    /// sequences with `U+FFFD REPLACEMENT CHARACTER`, or an error if the
+    /// value is not a `String`.
+    ///
+    /// The string is borrowed if it is valid UTF-8, and copied otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use extremedb::sql::engine::Engine;
+    /// # use extremedb::sql::value::Binary;
+    /// # use extremedb::{connection, database, runtime, sql};
+    /// # use extremedb::device::util;
+    /// # use std::borrow::Cow;
+    /// # fn main() -> extremedb::Result<()> {
+    /// #     let runtime = runtime::Runtime::start(vec![]);
+    /// #     let mut db_params = database::Params::new();
+    /// #     db_params
+    /// #         .ddl_dict_size(32768)
+    /// #         .max_classes(100)
+    /// #         .max_indexes(1000);
+    /// #     let mut devs = util::DeviceContainer::new();
+    /// #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+    /// #     let conn = connection::Connection::new(&db)?;
+    /// #     let engine = sql::engine::LocalEngine::new(&conn)?;
+    ///     engine.execute_statement("CREATE TABLE TestTable(i integer primary key, s string);", &[])?;
+    ///     engine.execute_statement("INSERT INTO TestTable(i, s) VALUES(1, 'Hello');", &[])?;
+    ///     // Not a valid UTF-8 sequence.
+    ///     engine.execute_statement(
+    ///         "INSERT INTO TestTable(i, s) VALUES(2, ?);",
+    ///         &[&Binary::new(&[0x48, 0x69, 0xff])],
+    ///     )?;
+    ///
+    ///     let ds = engine.execute_query("SELECT s FROM TestTable ORDER BY i;", &[])?.unwrap();
+    ///     let mut cur = ds.cursor()?;
+    ///
+    ///     assert!(cur.advance()?);
+    ///     {
+    ///         let rec = cur.current_record().unwrap();
+    ///         let val = rec.get_at(0)?;
+    ///         assert!(matches!(val.as_str_lossy()?, Cow::Borrowed("Hello")));
+    ///         assert_eq!(val.string_bytes()?, b"Hello");
+    ///     }
+    ///
+    ///     assert!(cur.advance()?);
+    ///     {
+    ///         let rec = cur.current_record().unwrap();
+    ///         let val = rec.get_at(0)?;
+    ///         assert!(val.as_str().is_err());
+    ///         assert_eq!(val.as_str_lossy()?, "Hi\u{fffd}");
+    ///         assert_eq!(val.string_bytes()?, &[0x48, 0x69, 0xff]);
+    ///     }
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn as_str_lossy(&self) -> Result<Cow<'_, str>> {
+        self.string_bytes().map(String::from_utf8_lossy)
+    }
+
+    /// Returns the raw bytes of a `String` value, or an error if the value
+    /// is not a `String`.
+    ///
+    /// Unlike [`as_str()`], this method does not require the string to be
+    /// valid UTF-8, and can be used to decode the strings stored in other
+    /// encodings.
+    ///
+    /// [`as_str()`]: #method.as_str
+    pub fn string_bytes(&self) -> Result<&[u8]> {
         if self.value_type()? != Type::String {
             Err(Error::new_sql(mcosql_error_code::INVALID_TYPE_CAST))
         } else {
-            let data = unsafe { slice::from_raw_parts(self.pointer()? as *const u8, self.size()?) };
-            str::from_utf8(data).or(Err(Error::new_sql(mcosql_error_code::INVALID_TYPE_CAST)))
+            Ok(unsafe { slice::from_raw_parts(self.pointer()? as *const u8, self.size()?) })
+        }
+    }
+
+    /// Returns the string representation of the value, avoiding the copy
+    /// when possible.
+    ///
+    /// Returns a borrowed string slice for a `String` value, and an owned
+    /// string produced by [`to_string()`] for values of other types.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use extremedb::sql::engine::Engine;
+    /// # use extremedb::{connection, database, device, runtime, sql};
+    /// # use extremedb::device::util;
+    /// # use std::borrow::Cow;
+    /// # fn main() -> extremedb::Result<()> {
+    /// #     let runtime = runtime::Runtime::start(vec![]);
+    /// #     let mut db_params = database::Params::new();
+    /// #     db_params
+    /// #         .ddl_dict_size(32768)
+    /// #         .max_classes(100)
+    /// #         .max_indexes(1000);
+    /// #     let mut devs = util::DeviceContainer::new();
+    /// #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+    /// #     let conn = connection::Connection::new(&db)?;
+    /// #     let engine = sql::engine::LocalEngine::new(&conn)?;
+    /// #     engine.execute_statement("CREATE TABLE TestTable(i integer, s string);", &[])?;
+    /// #     engine.execute_statement("INSERT INTO TestTable(i, s) VALUES(?, ?);", &[&1, &"Hello"])?;
+    /// #     let ds = engine.execute_query("SELECT i, s FROM TestTable;", &[])?;
+    /// #     let ds = ds.unwrap();
+    /// #     let mut cur = ds.cursor()?;
+    /// #     assert_eq!(cur.advance()?, true);
+    /// #     let rec = cur.current_record().unwrap();
+    ///     let i_val = rec.get_at(0)?;
+    ///     let s_val = rec.get_at(1)?;
+    ///
+    ///     assert!(matches!(i_val.to_cow_str()?, Cow::Owned(s) if s == "1"));
+    ///     assert!(matches!(s_val.to_cow_str()?, Cow::Borrowed("Hello")));
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`to_string()`]: #method.to_string
+    pub fn to_cow_str(&self) -> Result<Cow<'_, str>> {
+        if self.value_type()? == Type::String {
+            self.as_str().map(Cow::Borrowed)
+        } else {
+            self.to_string().map(Cow::Owned)
         }
     }
 
@@ -943,6 +1629,242 @@ impl<'a> Value<'a> {
         Ok(data.to_vec())
     }
 
+    /// Returns the bytes of the value, avoiding the copy when possible.
+    ///
+    /// Returns a borrowed byte slice for a `Binary` value. The data of a
+    /// `Blob` value may be split into segments, so it is read into an owned
+    /// buffer; the blob's read pointer is reset before reading, and is left
+    /// at the end of the data. For values of other types, an owned copy
+    /// produced by [`to_binary()`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use extremedb::sql::engine::Engine;
+    /// # use extremedb::sql::value::Binary;
+    /// # use extremedb::{connection, database, runtime, sql};
+    /// # use extremedb::device::util;
+    /// # use std::borrow::Cow;
+    /// # fn main() -> extremedb::Result<()> {
+    /// #     let runtime = runtime::Runtime::start(vec![]);
+    /// #     let mut db_params = database::Params::new();
+    /// #     db_params
+    /// #         .ddl_dict_size(32768)
+    /// #         .max_classes(100)
+    /// #         .max_indexes(1000);
+    /// #     let mut devs = util::DeviceContainer::new();
+    /// #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+    /// #     let conn = connection::Connection::new(&db)?;
+    /// #     let engine = sql::engine::LocalEngine::new(&conn)?;
+    ///     engine.execute_statement("CREATE TABLE TestTable(v varbinary, b blob);", &[])?;
+    ///
+    ///     let data: &[u8] = &[1, 2, 3];
+    ///     engine.execute_statement(
+    ///         "INSERT INTO TestTable(v, b) VALUES(?, ?);",
+    ///         &[&Binary::new(data), &data],
+    ///     )?;
+    ///
+    ///     let ds = engine.execute_query("SELECT v, b FROM TestTable;", &[])?.unwrap();
+    ///     let mut cur = ds.cursor()?;
+    ///     assert!(cur.advance()?);
+    ///
+    ///     let rec = cur.current_record().unwrap();
+    ///     let v_val = rec.get_at(0)?;
+    ///     let b_val = rec.get_at(1)?;
+    ///
+    ///     assert!(matches!(v_val.to_cow_bytes()?, Cow::Borrowed(&[1, 2, 3])));
+    ///     assert!(matches!(b_val.to_cow_bytes()?, Cow::Owned(b) if b == data));
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`to_binary()`]: #method.to_binary
+    pub fn to_cow_bytes(&self) -> Result<Cow<'_, [u8]>> {
+        match self.value_type()? {
+            Type::Binary => self.as_bytes().map(Cow::Borrowed),
+            Type::Blob => {
+                let blob = self.as_blob()?;
+                blob.reset()?;
+                blob.get(blob.total_size()?).map(Cow::Owned)
+            }
+            _ => self.to_binary().map(Cow::Owned),
+        }
+    }
+
+    /// Parses the contents of a `String` value as a JSON document.
+    ///
+    /// Returns an [`Error::Json`] if the string is not a valid JSON
+    /// document, or an `INVALID_TYPE_CAST` error if the value is not a
+    /// `String`.
+    ///
+    /// This method is only available when the `serde_json` feature is
+    /// enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use extremedb::sql::engine::Engine;
+    /// # use extremedb::{connection, database, device, runtime, sql, Error};
+    /// # use extremedb::device::util;
+    /// # fn main() -> extremedb::Result<()> {
+    /// #     let runtime = runtime::Runtime::start(vec![]);
+    /// #     let mut db_params = database::Params::new();
+    /// #     db_params
+    /// #         .ddl_dict_size(32768)
+    /// #         .max_classes(100)
+    /// #         .max_indexes(1000);
+    /// #     let mut devs = util::DeviceContainer::new();
+    /// #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+    /// #     let conn = connection::Connection::new(&db)?;
+    /// #     let engine = sql::engine::LocalEngine::new(&conn)?;
+    ///     engine.execute_statement("CREATE TABLE TestTable(i integer, doc string);", &[])?;
+    ///     engine.execute_statement(
+    ///         "INSERT INTO TestTable(i, doc) VALUES(?, ?);",
+    ///         &[&1, &r#"{"name": "sensor", "values": [1, 2, 3]}"#],
+    ///     )?;
+    ///     engine.execute_statement(
+    ///         "INSERT INTO TestTable(i, doc) VALUES(?, ?);",
+    ///         &[&2, &"{not json"],
+    ///     )?;
+    ///
+    ///     let ds = engine.execute_query("SELECT doc FROM TestTable ORDER BY i;", &[])?.unwrap();
+    ///     let mut cur = ds.cursor()?;
+    ///
+    ///     assert!(cur.advance()?);
+    ///     let rec = cur.current_record().unwrap();
+    ///     let doc = rec.get_at(0)?.to_json()?;
+    ///     assert_eq!(doc["name"], "sensor");
+    ///     assert_eq!(doc["values"][2], 3);
+    ///
+    ///     assert!(cur.advance()?);
+    ///     let rec = cur.current_record().unwrap();
+    ///     match rec.get_at(0)?.to_json() {
+    ///         Err(Error::Json(_)) => (),
+    ///         _ => panic!("JSON error expected"),
+    ///     }
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Error::Json`]: ../../enum.Error.html#variant.Json
+    #[cfg(feature = "serde_json")]
+    pub fn to_json(&self) -> Result<serde_json::Value> {
+        serde_json::from_str(self.as_str()?).map_err(Error::Json)
+    }
+
+    /// Converts the value to a JSON value.
+    ///
+    /// Unlike [`to_json()`], which parses the JSON document stored in a
+    /// string, this method maps the SQL value itself onto the JSON data
+    /// model:
+    ///
+    /// - `NULL` is converted to `null`;
+    /// - booleans, integers, and reals are converted to JSON booleans and
+    ///   numbers; the real values which cannot be represented in JSON
+    ///   (infinities and `NaN`s) are rejected;
+    /// - numerics are converted to strings, to preserve their precision;
+    /// - timestamps are converted to numbers of system ticks;
+    /// - strings are converted to JSON strings;
+    /// - binary values are converted to the standard Base64 strings with
+    ///   padding;
+    /// - arrays are converted to JSON arrays, recursively.
+    ///
+    /// Blobs, sequences, lists, and nested data sources are not currently
+    /// supported. Unsupported values are rejected with an
+    /// `INVALID_TYPE_CAST` error.
+    ///
+    /// This method is only available when the `serde_json` feature is
+    /// enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use extremedb::sql::engine::Engine;
+    /// # use extremedb::sql::value::Binary;
+    /// # use extremedb::{connection, database, device, runtime, sql};
+    /// # use extremedb::device::util;
+    /// # use serde_json::json;
+    /// # fn main() -> extremedb::Result<()> {
+    /// #     let runtime = runtime::Runtime::start(vec![]);
+    /// #     let mut db_params = database::Params::new();
+    /// #     db_params
+    /// #         .ddl_dict_size(32768)
+    /// #         .max_classes(100)
+    /// #         .max_indexes(1000);
+    /// #     let mut devs = util::DeviceContainer::new();
+    /// #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+    /// #     let conn = connection::Connection::new(&db)?;
+    /// #     let engine = sql::engine::LocalEngine::new(&conn)?;
+    ///     engine.execute_statement(
+    ///         "CREATE TABLE TestTable(i integer, d double, s string, b varbinary, a array(int), n integer);",
+    ///         &[],
+    ///     )?;
+    ///
+    ///     let ints: &[i32] = &[1, 2, 3];
+    ///     engine.execute_statement(
+    ///         "INSERT INTO TestTable(i, d, s, b, a, n) VALUES(?, ?, ?, ?, ?, NULL);",
+    ///         &[&42, &1.5, &"Hello", &Binary::new(b"Hi!"), &ints],
+    ///     )?;
+    ///
+    ///     let ds = engine.execute_query("SELECT * FROM TestTable;", &[])?.unwrap();
+    ///     let mut cur = ds.cursor()?;
+    ///     assert!(cur.advance()?);
+    ///
+    ///     let rec = cur.current_record().unwrap();
+    ///     let mut obj = serde_json::Map::new();
+    ///     for col in rec.iter_columns() {
+    ///         let (name, _, val) = col?;
+    ///         obj.insert(name, val.to_json_value()?);
+    ///     }
+    ///
+    ///     assert_eq!(
+    ///         serde_json::Value::Object(obj),
+    ///         json!({
+    ///             "i": 42,
+    ///             "d": 1.5,
+    ///             "s": "Hello",
+    ///             "b": "SGkh",
+    ///             "a": [1, 2, 3],
+    ///             "n": null,
+    ///         })
+    ///     );
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`to_json()`]: #method.to_json
+    #[cfg(feature = "serde_json")]
+    pub fn to_json_value(&self) -> Result<serde_json::Value> {
+        use serde_json::Value as Json;
+
+        let invalid = || Error::new_sql(mcosql_error_code::INVALID_TYPE_CAST);
+
+        match self.value_type()? {
+            Type::Null => Ok(Json::Null),
+            Type::Bool => self.to_bool().map(Json::from),
+            Type::Int1 | Type::Int2 | Type::Int4 | Type::Int8 => self.to_i64().map(Json::from),
+            Type::UInt1 | Type::UInt2 | Type::UInt4 | Type::UInt8 => self.to_u64().map(Json::from),
+            Type::Real4 | Type::Real8 => serde_json::Number::from_f64(self.to_real()?)
+                .map(Json::Number)
+                .ok_or_else(invalid),
+            Type::Numeric => Ok(Json::String(self.to_numeric()?.to_string())),
+            Type::Time => self.to_date_time().map(Json::from),
+            Type::String => self.as_str().map(Json::from),
+            Type::Binary => Ok(Json::String(base64_encode(self.as_bytes()?))),
+            Type::Array => {
+                let array = self.as_array()?;
+                let len = array.len()?;
+                let mut elems = Vec::with_capacity(len);
+                for i in 0..len {
+                    let elem = array.get_at(i)?;
+                    elems.push(elem.to_json_value()?);
+                }
+                Ok(Json::Array(elems))
+            }
+            Type::Blob | Type::DataSource | Type::List | Type::Sequence => Err(invalid()),
+        }
+    }
+
     /// Returns a byte slice pointing to the contents of a `Binary` value,
     /// or an error if the value is not a `Binary`.
     pub fn as_bytes(&self) -> Result<&[u8]> {
@@ -953,6 +1875,127 @@ impl<'a> Value<'a> {
         }
     }
 
+    /// Returns a reference to the contents of a `Binary` value, or an error
+    /// if the value is not a `Binary`.
+    ///
+    /// Unlike [`as_bytes()`], this method returns a [`BinaryRef`], which
+    /// can be passed to the functions accepting `AsRef<[u8]>` without
+    /// copying the data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use extremedb::sql::engine::Engine;
+    /// # use extremedb::sql::value::Binary;
+    /// # use extremedb::{connection, database, runtime, sql};
+    /// # use extremedb::device::util;
+    /// # fn main() -> extremedb::Result<()> {
+    /// #     let runtime = runtime::Runtime::start(vec![]);
+    /// #     let mut db_params = database::Params::new();
+    /// #     db_params
+    /// #         .ddl_dict_size(32768)
+    /// #         .max_classes(100)
+    /// #         .max_indexes(1000);
+    /// #     let mut devs = util::DeviceContainer::new();
+    /// #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+    /// #     let conn = connection::Connection::new(&db)?;
+    /// #     let engine = sql::engine::LocalEngine::new(&conn)?;
+    ///     fn checksum<T: AsRef<[u8]>>(data: T) -> u32 {
+    ///         data.as_ref().iter().map(|&b| b as u32).sum()
+    ///     }
+    ///
+    ///     engine.execute_statement("CREATE TABLE TestTable(b varbinary);", &[])?;
+    ///     engine.execute_statement(
+    ///         "INSERT INTO TestTable(b) VALUES(?);",
+    ///         &[&Binary::new(&[1, 2, 3])],
+    ///     )?;
+    ///
+    ///     let ds = engine.execute_query("SELECT b FROM TestTable;", &[])?.unwrap();
+    ///     let mut cur = ds.cursor()?;
+    ///     assert!(cur.advance()?);
+    ///
+    ///     let rec = cur.current_record().unwrap();
+    ///     let val = rec.get_at(0)?;
+    ///     let bin = val.as_binary_ref()?;
+    ///     assert_eq!(bin.len(), 3);
+    ///     assert_eq!(checksum(bin), 6);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`as_bytes()`]: #method.as_bytes
+    /// [`BinaryRef`]: ./struct.BinaryRef.html
+    pub fn as_binary_ref(&self) -> Result<BinaryRef<'_>> {
+        self.as_bytes().map(BinaryRef)
+    }
+
+    /// Interprets a 4-byte `Binary` value as an IPv4 address.
+    ///
+    /// Returns an error if the value is not a `Binary`, or its length is not
+    /// 4 bytes. The address octets are expected in the network byte order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use extremedb::sql::engine::Engine;
+    /// # use extremedb::sql::value::Binary;
+    /// # use extremedb::{connection, database, device, runtime, sql};
+    /// # use extremedb::device::util;
+    /// # use std::net::Ipv4Addr;
+    /// # fn main() -> extremedb::Result<()> {
+    /// #     let runtime = runtime::Runtime::start(vec![]);
+    /// #     let mut db_params = database::Params::new();
+    /// #     db_params
+    /// #         .ddl_dict_size(32768)
+    /// #         .max_classes(100)
+    /// #         .max_indexes(1000);
+    /// #     let mut devs = util::DeviceContainer::new();
+    /// #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+    /// #     let conn = connection::Connection::new(&db)?;
+    /// #     let engine = sql::engine::LocalEngine::new(&conn)?;
+    ///     engine.execute_statement("CREATE TABLE TestTable(b varbinary);", &[])?;
+    ///
+    ///     let addr = Ipv4Addr::new(192, 168, 0, 1);
+    ///     let octets = addr.octets();
+    ///     engine.execute_statement(
+    ///         "INSERT INTO TestTable(b) VALUES(?);",
+    ///         &[&Binary::new(&octets)],
+    ///     )?;
+    ///     engine.execute_statement(
+    ///         "INSERT INTO TestTable(b) VALUES(?);",
+    ///         &[&Binary::new(&[1, 2, 3])],
+    ///     )?;
+    ///
+    ///     let ds = engine.execute_query("SELECT b FROM TestTable;", &[])?.unwrap();
+    ///     let mut cur = ds.cursor()?;
+    ///
+    ///     assert!(cur.advance()?);
+    ///     let rec = cur.current_record().unwrap();
+    ///     assert_eq!(rec.get_at(0)?.to_ipv4()?, addr);
+    ///
+    ///     // Wrong length.
+    ///     assert!(cur.advance()?);
+    ///     let rec = cur.current_record().unwrap();
+    ///     assert!(rec.get_at(0)?.to_ipv4().is_err());
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn to_ipv4(&self) -> Result<Ipv4Addr> {
+        let octets = <[u8; 4]>::try_from(self.as_bytes()?)
+            .or(Err(Error::new_sql(mcosql_error_code::INVALID_TYPE_CAST)))?;
+        Ok(Ipv4Addr::from(octets))
+    }
+
+    /// Interprets a 16-byte `Binary` value as an IPv6 address.
+    ///
+    /// Returns an error if the value is not a `Binary`, or its length is not
+    /// 16 bytes. The address octets are expected in the network byte order.
+    pub fn to_ipv6(&self) -> Result<Ipv6Addr> {
+        let octets = <[u8; 16]>::try_from(self.as_bytes()?)
+            .or(Err(Error::new_sql(mcosql_error_code::INVALID_TYPE_CAST)))?;
+        Ok(Ipv6Addr::from(octets))
+    }
+
     /// Casts the value to `Array` if it has the `Array` type; returns
     /// an error otherwise.
     pub fn as_array(&self) -> Result<&Array> {
@@ -983,10 +2026,200 @@ impl<'a> Value<'a> {
         }
     }
 
-    unsafe fn pointer(&self) -> Result<*const c_void> {
-        let mut p = MaybeUninit::uninit();
-        result_from_code(exdb_sys::mcosql_rs_value_ptr(self.h, p.as_mut_ptr()))
-            .and(Ok(p.assume_init()))
+    /// Compares the value with an integer.
+    ///
+    /// The value is converted using [`to_i64()`]; an error is returned if
+    /// the conversion fails. SQL `null` values are not equal to anything.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use extremedb::sql::engine::Engine;
+    /// # use extremedb::{connection, database, device, runtime, sql};
+    /// # use extremedb::device::util;
+    /// # fn main() -> extremedb::Result<()> {
+    /// #     let runtime = runtime::Runtime::start(vec![]);
+    /// #     let mut db_params = database::Params::new();
+    /// #     db_params
+    /// #         .ddl_dict_size(32768)
+    /// #         .max_classes(100)
+    /// #         .max_indexes(1000);
+    /// #     let mut devs = util::DeviceContainer::new();
+    /// #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+    /// #     let conn = connection::Connection::new(&db)?;
+    /// #     let engine = sql::engine::LocalEngine::new(&conn)?;
+    /// #     engine.execute_statement("CREATE TABLE TestTable(i integer, s string);", &[])?;
+    /// #     engine.execute_statement("INSERT INTO TestTable(i, s) VALUES(?, ?);", &[&1, &"Hello"])?;
+    /// #     let ds = engine.execute_query("SELECT i, s FROM TestTable;", &[])?;
+    /// #     let ds = ds.unwrap();
+    /// #     let mut cur = ds.cursor()?;
+    /// #     assert_eq!(cur.advance()?, true);
+    /// #     let rec = cur.current_record().unwrap();
+    ///     let i_val = rec.get_at(0)?;
+    ///     let s_val = rec.get_at(1)?;
+    ///
+    ///     assert!(i_val.eq_i64(1)?);
+    ///     assert!(!i_val.eq_i64(2)?);
+    ///     assert!(i_val.eq_native(&1u8)?);
+    ///
+    ///     assert!(s_val.eq_str("Hello")?);
+    ///     assert!(!s_val.eq_str("World")?);
+    ///     assert!(s_val.eq_native(&String::from("Hello"))?);
+    ///
+    ///     // Strings cannot be compared with integer values.
+    ///     assert!(i_val.eq_str("1").is_err());
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`to_i64()`]: #method.to_i64
+    pub fn eq_i64(&self, other: i64) -> Result<bool> {
+        if self.is_null() {
+            Ok(false)
+        } else {
+            Ok(self.to_i64()? == other)
+        }
+    }
+
+    /// Compares a `String` value with a string slice.
+    ///
+    /// Returns an error if the value is not a `String`. SQL `null` values
+    /// are not equal to anything.
+    pub fn eq_str(&self, other: &str) -> Result<bool> {
+        if self.is_null() {
+            Ok(false)
+        } else {
+            Ok(self.as_str()? == other)
+        }
+    }
+
+    /// Compares the value with a native Rust value.
+    ///
+    /// The value is converted to `T` using the [`FromValue`] trait; an error
+    /// is returned if the conversion fails. SQL `null` values are not equal
+    /// to anything.
+    ///
+    /// [`FromValue`]: ./trait.FromValue.html
+    pub fn eq_native<T: PartialEq + FromValue>(&self, other: &T) -> Result<bool> {
+        if self.is_null() {
+            Ok(false)
+        } else {
+            Ok(T::from_value(self)? == *other)
+        }
+    }
+
+    /// Compares this value with `other`, following the SQL ordering rules.
+    ///
+    /// Values of the compatible types are compared as follows:
+    ///
+    /// - integers are compared by their numeric values, regardless of their
+    ///   widths and signedness;
+    /// - integers, reals, and numerics can be compared with each other; the
+    ///   comparison is exact unless one of the operands is a real;
+    /// - booleans, timestamps, strings, and binary values are compared with
+    ///   the values of the same type; strings and binary values are compared
+    ///   lexicographically, byte by byte.
+    ///
+    /// A `NULL` value is equal to another `NULL`, and is less than any other
+    /// value. Comparing the values of incompatible types (for example, a
+    /// string and an integer), or a real `NaN`, returns an
+    /// `INVALID_OPERATION` error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use extremedb::sql::engine::Engine;
+    /// # use extremedb::{connection, database, runtime, sql};
+    /// # use extremedb::device::util;
+    /// # use std::cmp::Ordering;
+    /// # fn main() -> extremedb::Result<()> {
+    /// #     let runtime = runtime::Runtime::start(vec![]);
+    /// #     let mut db_params = database::Params::new();
+    /// #     db_params
+    /// #         .ddl_dict_size(32768)
+    /// #         .max_classes(100)
+    /// #         .max_indexes(1000);
+    /// #     let mut devs = util::DeviceContainer::new();
+    /// #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+    /// #     let conn = connection::Connection::new(&db)?;
+    /// #     let engine = sql::engine::LocalEngine::new(&conn)?;
+    ///     engine.execute_statement(
+    ///         "CREATE TABLE TestTable(i integer, j integer, r double, s string, t string);",
+    ///         &[],
+    ///     )?;
+    ///     engine.execute_statement(
+    ///         "INSERT INTO TestTable(i, j, r, s, t) VALUES(?, ?, ?, ?, ?);",
+    ///         &[&10, &10, &10.5, &"apple", &"banana"],
+    ///     )?;
+    ///
+    ///     let ds = engine.execute_query("SELECT i, j, r, s, t FROM TestTable;", &[])?.unwrap();
+    ///     let mut cur = ds.cursor()?;
+    ///     assert!(cur.advance()?);
+    ///
+    ///     let rec = cur.current_record().unwrap();
+    ///     let i = rec.get_at(0)?;
+    ///     let j = rec.get_at(1)?;
+    ///     let r = rec.get_at(2)?;
+    ///     let s = rec.get_at(3)?;
+    ///     let t = rec.get_at(4)?;
+    ///
+    ///     assert_eq!(i.compare(&j)?, Ordering::Equal);
+    ///     assert_eq!(i.compare(&r)?, Ordering::Less);
+    ///     assert_eq!(r.compare(&i)?, Ordering::Greater);
+    ///     assert_eq!(s.compare(&t)?, Ordering::Less);
+    ///
+    ///     // Incompatible types.
+    ///     assert!(i.compare(&s).is_err());
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn compare(&self, other: &Value) -> Result<Ordering> {
+        use CompareKey::*;
+
+        let ord = match (self.compare_key()?, other.compare_key()?) {
+            (Null, Null) => Some(Ordering::Equal),
+            (Null, _) => Some(Ordering::Less),
+            (_, Null) => Some(Ordering::Greater),
+            (Bool(a), Bool(b)) => Some(a.cmp(&b)),
+            (Int(a), Int(b)) => Some(a.cmp(&b)),
+            (Num(a), Num(b)) => Some(a.cmp(&b)),
+            (Int(a), Num(b)) => Some(CompareKey::cmp_int_numeric(a, b)),
+            (Num(a), Int(b)) => Some(CompareKey::cmp_int_numeric(b, a).reverse()),
+            (Time(a), Time(b)) => Some(a.cmp(&b)),
+            (Str(a), Str(b)) => Some(a.cmp(b)),
+            (Bytes(a), Bytes(b)) => Some(a.cmp(b)),
+            (a, b) => match (a.as_real(), b.as_real()) {
+                (Some(a), Some(b)) => a.partial_cmp(&b),
+                _ => None,
+            },
+        };
+
+        ord.ok_or(Error::new_sql(mcosql_error_code::INVALID_OPERATION))
+    }
+
+    fn compare_key(&self) -> Result<CompareKey<'_>> {
+        Ok(match self.value_type()? {
+            Type::Null => CompareKey::Null,
+            Type::Bool => CompareKey::Bool(self.to_bool()?),
+            Type::Int1 | Type::Int2 | Type::Int4 | Type::Int8 => {
+                CompareKey::Int(self.to_i64()? as i128)
+            }
+            Type::UInt1 | Type::UInt2 | Type::UInt4 | Type::UInt8 => {
+                CompareKey::Int(self.to_u64()? as i128)
+            }
+            Type::Real4 | Type::Real8 => CompareKey::Real(self.to_real()?),
+            Type::Numeric => CompareKey::Num(self.to_numeric()?),
+            Type::Time => CompareKey::Time(self.to_date_time()?),
+            Type::String => CompareKey::Str(self.as_str()?),
+            Type::Binary => CompareKey::Bytes(self.as_bytes()?),
+            _ => CompareKey::Other,
+        })
+    }
+
+    unsafe fn pointer(&self) -> Result<*const c_void> {
+        let mut p = MaybeUninit::uninit();
+        result_from_code(exdb_sys::mcosql_rs_value_ptr(self.h, p.as_mut_ptr()))
+            .and(Ok(p.assume_init()))
     }
 
     // Highly unsafe: must be released using the allocator which was used to allocate the value;
@@ -996,6 +2229,63 @@ impl<'a> Value<'a> {
     }
 }
 
+// Encodes the bytes using the standard Base64 alphabet, with padding.
+#[cfg(feature = "serde_json")]
+fn base64_encode(data: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut ret = String::with_capacity(data.len().div_ceil(3) * 4);
+
+    for chunk in data.chunks(3) {
+        let b = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+        let n = (b[0] as u32) << 16 | (b[1] as u32) << 8 | b[2] as u32;
+
+        for i in 0..4 {
+            if i <= chunk.len() {
+                ret.push(ALPHABET[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                ret.push('=');
+            }
+        }
+    }
+
+    ret
+}
+
+// A value converted for the comparison by Value::compare().
+enum CompareKey<'v> {
+    Null,
+    Bool(bool),
+    Int(i128),
+    Real(f64),
+    Num(Numeric),
+    Time(u64),
+    Str(&'v str),
+    Bytes(&'v [u8]),
+    Other,
+}
+
+impl<'v> CompareKey<'v> {
+    fn as_real(&self) -> Option<f64> {
+        match self {
+            CompareKey::Int(i) => Some(*i as f64),
+            CompareKey::Real(r) => Some(*r),
+            CompareKey::Num(n) => Some((*n).into()),
+            _ => None,
+        }
+    }
+
+    // Integers which do not fit in i64 can only come from u64 values, and
+    // are greater than any numeric.
+    fn cmp_int_numeric(i: i128, n: Numeric) -> Ordering {
+        i64::try_from(i).map_or(Ordering::Greater, |i| Numeric::from_int(i).cmp(&n))
+    }
+}
+
 impl<'a> From<Array<'a>> for Value<'a> {
     fn from(array: Array<'a>) -> Self {
         array.val
@@ -1014,6 +2304,97 @@ impl<'a> From<Blob<'a>> for Value<'a> {
     }
 }
 
+/// Formats the value in a human-readable form, for logging and debugging.
+///
+/// `NULL` values are written as `NULL`, `Binary` values as lowercase
+/// hexadecimal digits, and arrays as a bracketed, comma-separated list of
+/// their elements. Values of the other types are formatted using
+/// [`Value::to_string()`]; if the SQL engine cannot convert a value to a
+/// string, its type is written in angle brackets instead (e.g. `<Blob>`).
+///
+/// # Examples
+///
+/// ```
+/// # use extremedb::sql::engine::Engine;
+/// # use extremedb::sql::value::Binary;
+/// # use extremedb::{connection, database, runtime, sql};
+/// # use extremedb::device::util;
+/// # fn main() -> extremedb::Result<()> {
+/// #     let runtime = runtime::Runtime::start(vec![]);
+/// #     let mut db_params = database::Params::new();
+/// #     db_params
+/// #         .ddl_dict_size(32768)
+/// #         .max_classes(100)
+/// #         .max_indexes(1000);
+/// #     let mut devs = util::DeviceContainer::new();
+/// #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+/// #     let conn = connection::Connection::new(&db)?;
+/// #     let engine = sql::engine::LocalEngine::new(&conn)?;
+///     engine.execute_statement(
+///         "CREATE TABLE TestTable(i integer, s string, b varbinary, a array(int), n integer);",
+///         &[],
+///     )?;
+///
+///     let ints: &[i32] = &[1, 2, 3];
+///     engine.execute_statement(
+///         "INSERT INTO TestTable(i, s, b, a, n) VALUES(?, ?, ?, ?, NULL);",
+///         &[&42, &"Hello", &Binary::new(&[0x0a, 0xff]), &ints],
+///     )?;
+///
+///     let ds = engine.execute_query("SELECT i, s, b, a, n FROM TestTable;", &[])?.unwrap();
+///     let mut cur = ds.cursor()?;
+///     assert!(cur.advance()?);
+///
+///     let rec = cur.current_record().unwrap();
+///     assert_eq!(format!("{}", *rec.get_at(0)?), "42");
+///     assert_eq!(format!("{}", *rec.get_at(1)?), "Hello");
+///     assert_eq!(format!("{}", *rec.get_at(2)?), "0aff");
+///     assert_eq!(format!("{}", *rec.get_at(3)?), "[1, 2, 3]");
+///     assert_eq!(format!("{}", *rec.get_at(4)?), "NULL");
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [`Value::to_string()`]: ./struct.Value.html#method.to_string
+impl<'a> Display for Value<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::result::Result<(), FmtError> {
+        let ty = match self.value_type() {
+            Ok(ty) => ty,
+            Err(_) => return write!(f, "<?>"),
+        };
+
+        match ty {
+            Type::Null => write!(f, "NULL"),
+            Type::Binary => match self.as_bytes() {
+                Ok(bytes) => bytes.iter().try_for_each(|b| write!(f, "{:02x}", b)),
+                Err(_) => write!(f, "<{:?}>", ty),
+            },
+            Type::Array => {
+                let (array, len) = match self.as_array().and_then(|a| Ok((a, a.len()?))) {
+                    Ok(res) => res,
+                    Err(_) => return write!(f, "<{:?}>", ty),
+                };
+
+                write!(f, "[")?;
+                for i in 0..len {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    match array.get_at(i) {
+                        Ok(elem) => write!(f, "{}", *elem)?,
+                        Err(_) => write!(f, "<?>")?,
+                    }
+                }
+                write!(f, "]")
+            }
+            _ => match self.to_string() {
+                Ok(s) => write!(f, "{}", s),
+                Err(_) => write!(f, "<{:?}>", ty),
+            },
+        }
+    }
+}
+
 /// An SQL value reference.
 ///
 /// In addition to the value itself, a value reference holds a reference to the
@@ -1032,8 +2413,130 @@ impl<'a> Ref<'a> {
         }
     }
 
-    fn allocator(&'a self) -> AllocatorRef<'a> {
-        AllocatorRef::from_handle(self.r.allocator, self)
+    /// Returns a reference to the allocator which owns the referenced value.
+    ///
+    /// The allocator can be used to produce other values (for example, using
+    /// the [`ToValue`] trait) which share the referenced value's lifetime.
+    /// Such values are released when the allocator is destroyed, and cannot
+    /// outlive this reference.
+    ///
+    /// Returns `None` if this reference does not own the value it refers to,
+    /// as is the case with the sequence elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use extremedb::sql::engine::Engine;
+    /// # use extremedb::sql::value::ToValue;
+    /// # use extremedb::{connection, database, device, runtime, sql};
+    /// # use extremedb::device::util;
+    /// # fn main() -> extremedb::Result<()> {
+    /// #     let runtime = runtime::Runtime::start(vec![]);
+    /// #     let mut db_params = database::Params::new();
+    /// #     db_params
+    /// #         .ddl_dict_size(32768)
+    /// #         .max_classes(100)
+    /// #         .max_indexes(1000);
+    /// #     let mut devs = util::DeviceContainer::new();
+    /// #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+    /// #     let conn = connection::Connection::new(&db)?;
+    /// #     let engine = sql::engine::LocalEngine::new(&conn)?;
+    /// #     engine.execute_statement("CREATE TABLE TestTable(i integer);", &[])?;
+    /// #     engine.execute_statement("INSERT INTO TestTable(i) VALUES(?);", &[&1])?;
+    /// #     let ds = engine.execute_query("SELECT i FROM TestTable;", &[])?;
+    /// #     let ds = ds.unwrap();
+    /// #     let mut cur = ds.cursor()?;
+    /// #     assert_eq!(cur.advance()?, true);
+    /// #     let rec = cur.current_record().unwrap();
+    ///     let val = rec.get_at(0)?;
+    ///     let alloc = val.allocator().unwrap();
+    ///
+    ///     // The derived value is tied to the scope of `val`.
+    ///     let derived = (val.to_i64()? + 1).to_value(alloc)?;
+    ///     assert_eq!(derived.to_i64()?, 2);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`ToValue`]: ./trait.ToValue.html
+    pub fn allocator(&self) -> Option<AllocatorRef<'_>> {
+        if self.r.allocator.is_null() {
+            None
+        } else {
+            Some(AllocatorRef::from_handle(self.r.allocator, self))
+        }
+    }
+
+    /// Creates a deep copy of the referenced value which does not depend on
+    /// the value's allocator.
+    ///
+    /// The returned [`OwnedValue`] can outlive this reference, as well as
+    /// the record and the cursor the value was read from. Strings, binary
+    /// values, and arrays (including their elements) are fully copied. The
+    /// values which cannot be detached (see [`OwnedValue`]) are rejected with
+    /// an `INVALID_TYPE_CAST` error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use extremedb::sql::engine::Engine;
+    /// # use extremedb::sql::value::{Binary, OwnedValue};
+    /// # use extremedb::{connection, database, device, runtime, sql};
+    /// # use extremedb::device::util;
+    /// # fn main() -> extremedb::Result<()> {
+    /// #     let runtime = runtime::Runtime::start(vec![]);
+    /// #     let mut db_params = database::Params::new();
+    /// #     db_params
+    /// #         .ddl_dict_size(32768)
+    /// #         .max_classes(100)
+    /// #         .max_indexes(1000);
+    /// #     let mut devs = util::DeviceContainer::new();
+    /// #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+    /// #     let conn = connection::Connection::new(&db)?;
+    /// #     let engine = sql::engine::LocalEngine::new(&conn)?;
+    ///     engine.execute_statement(
+    ///         "CREATE TABLE TestTable(i integer primary key, a array(int), b varbinary);",
+    ///         &[],
+    ///     )?;
+    ///
+    ///     let first: &[i32] = &[1, 2, 3];
+    ///     let second: &[i32] = &[4, 5];
+    ///     engine.execute_statement(
+    ///         "INSERT INTO TestTable(i, a, b) VALUES(?, ?, ?);",
+    ///         &[&1, &first, &Binary::new(&[0xaa, 0xbb])],
+    ///     )?;
+    ///     engine.execute_statement(
+    ///         "INSERT INTO TestTable(i, a, b) VALUES(?, ?, ?);",
+    ///         &[&2, &second, &Binary::new(&[0xcc])],
+    ///     )?;
+    ///
+    ///     let ds = engine.execute_query("SELECT a, b FROM TestTable ORDER BY i;", &[])?.unwrap();
+    ///     let mut cur = ds.cursor()?;
+    ///
+    ///     assert!(cur.advance()?);
+    ///     let rec = cur.current_record().unwrap();
+    ///     let owned_a = rec.get_at(0)?.to_owned_value()?;
+    ///     let owned_b = rec.get_at(1)?.to_owned_value()?;
+    ///
+    ///     // The copies remain valid after the cursor is advanced.
+    ///     assert!(cur.advance()?);
+    ///     assert!(!cur.advance()?);
+    ///
+    ///     match owned_a {
+    ///         OwnedValue::Array(elems) => assert!(matches!(
+    ///             elems.as_slice(),
+    ///             [OwnedValue::Int(1), OwnedValue::Int(2), OwnedValue::Int(3)]
+    ///         )),
+    ///         _ => panic!("unexpected value"),
+    ///     }
+    ///     assert!(matches!(owned_b, OwnedValue::Binary(b) if b == [0xaa, 0xbb]));
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`OwnedValue`]: ./enum.OwnedValue.html
+    pub fn to_owned_value(&self) -> Result<OwnedValue> {
+        OwnedValue::from_value(self)
     }
 
     fn defused_clone(&'a self) -> Ref<'a> {
@@ -1051,10 +2554,11 @@ impl<'a> Ref<'a> {
     }
 
     fn release_value(&mut self) {
-        if !self.is_null_ref() && !self.r.allocator.is_null() {
-            let alloc = self.allocator();
-            let res = unsafe { self.release(alloc) };
-            debug_assert!(res.is_ok());
+        if !self.is_null_ref() {
+            if let Some(alloc) = self.allocator() {
+                let res = unsafe { self.release(alloc) };
+                debug_assert!(res.is_ok());
+            }
         }
         self.r.ref_ = ptr::null_mut();
     }
@@ -1100,22 +2604,116 @@ pub struct Array<'a> {
 
 impl<'a> Array<'a> {
     fn new<T: ArrayElem>(items: &[T], alloc: AllocatorRef<'a>) -> Result<Self> {
+        let mut ret = Array::with_len::<T>(items.len(), alloc)?;
+        ret.set_body(items).and(Ok(ret))
+    }
+
+    fn with_len<T: ArrayElem>(len: usize, alloc: AllocatorRef<'a>) -> Result<Self> {
         let mut h = MaybeUninit::uninit();
 
         result_from_code(unsafe {
             exdb_sys::mcosql_rs_value_create_array(
                 alloc.h,
                 T::static_type() as mcosql_column_type::Type,
-                items.len() as exdb_sys::size_t,
+                len as exdb_sys::size_t,
                 h.as_mut_ptr(),
             )
         })?;
 
-        let mut ret = Array {
+        Ok(Array {
             val: Value::from_handle(unsafe { h.assume_init() }, alloc),
-        };
+        })
+    }
 
-        ret.set_body(items).and(Ok(ret))
+    /// Creates an array from the items produced by an iterator.
+    ///
+    /// The length of the array is taken from the iterator, which must
+    /// implement `ExactSizeIterator`. The elements of the non-plain types,
+    /// such as strings, are converted and stored as the iterator is consumed,
+    /// without an intermediate collection. Plain elements are buffered
+    /// before being copied into the array in a single call. If the iterator
+    /// yields a number of items different from its reported length, a
+    /// `RUNTIME_ERROR` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use extremedb::sql::engine::Engine;
+    /// # use extremedb::sql::value::{Array, ToValue};
+    /// # use extremedb::{connection, database, runtime, sql};
+    /// # use extremedb::device::util;
+    /// # fn main() -> extremedb::Result<()> {
+    /// #     let runtime = runtime::Runtime::start(vec![]);
+    /// #     let mut db_params = database::Params::new();
+    /// #     db_params
+    /// #         .ddl_dict_size(32768)
+    /// #         .max_classes(100)
+    /// #         .max_indexes(1000);
+    /// #     let mut devs = util::DeviceContainer::new();
+    /// #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+    /// #     let conn = connection::Connection::new(&db)?;
+    /// #     let engine = sql::engine::LocalEngine::new(&conn)?;
+    ///     engine.execute_statement("CREATE TABLE TestTable(i int);", &[])?;
+    ///     engine.execute_statement("INSERT INTO TestTable(i) VALUES(1);", &[])?;
+    ///
+    ///     let ds = engine.execute_query("SELECT i FROM TestTable;", &[])?.unwrap();
+    ///     let mut cur = ds.cursor()?;
+    ///     assert!(cur.advance()?);
+    ///
+    ///     let rec = cur.current_record().unwrap();
+    ///     let val = rec.get_at(0)?;
+    ///     let alloc = val.allocator().unwrap();
+    ///
+    ///     let from_iter = Array::from_iter((0..5).map(|i: i32| i * 10), alloc)?;
+    ///     let slice: &[i32] = &[0, 10, 20, 30, 40];
+    ///     let from_slice = slice.to_value(alloc)?;
+    ///
+    ///     assert_eq!(from_iter.len()?, 5);
+    ///     assert_eq!(from_iter.to_vec::<i32>()?, from_slice.as_array()?.to_vec::<i32>()?);
+    ///
+    ///     let names = Array::from_iter(vec!["a", "b", "c"].into_iter(), alloc)?;
+    ///     assert_eq!(names.get_at(1)?.to_string()?, "b");
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn from_iter<T, I>(iter: I, alloc: AllocatorRef<'a>) -> Result<Self>
+    where
+        T: ArrayElem,
+        I: IntoIterator<Item = T>,
+        I::IntoIter: ExactSizeIterator,
+    {
+        let iter = iter.into_iter();
+        let len = iter.len();
+        let mut ret = Array::with_len::<T>(len, alloc)?;
+
+        if ret.is_plain() {
+            let body: Vec<T> = iter.collect();
+            if body.len() != len {
+                return Err(Error::new_sql(mcosql_error_code::RUNTIME_ERROR));
+            }
+            ret.set_body_plain(&body)?;
+        } else {
+            // Use the array's own allocator, as in set_body_values().
+            let elem_alloc = ret.allocator()?;
+            let mut n = 0;
+
+            for (i, item) in iter.enumerate() {
+                if i >= len {
+                    return Err(Error::new_sql(mcosql_error_code::RUNTIME_ERROR));
+                }
+                let val = item.to_value(elem_alloc)?;
+                result_from_code(unsafe {
+                    exdb_sys::mcosql_rs_array_set_at(ret.val.h, i as exdb_sys::size_t, val.h)
+                })?;
+                n = i + 1;
+            }
+
+            if n != len {
+                return Err(Error::new_sql(mcosql_error_code::RUNTIME_ERROR));
+            }
+        }
+
+        Ok(ret)
     }
 
     fn is_plain(&self) -> bool {
@@ -1147,6 +2745,12 @@ impl<'a> Array<'a> {
     }
 
     /// Returns the element at the given index.
+    ///
+    /// The index is not checked by this method; an out-of-range index is
+    /// rejected by the SQL engine with an error. Use [`get()`] to handle
+    /// out-of-range indexes explicitly.
+    ///
+    /// [`get()`]: #method.get
     pub fn get_at(&self, at: usize) -> Result<Ref> {
         let mut h = MaybeUninit::uninit();
 
@@ -1156,6 +2760,303 @@ impl<'a> Array<'a> {
         .and(Ok(Ref::from_handle(unsafe { h.assume_init() }, self)))
     }
 
+    /// Returns the element at the given index, or `None` if the index is
+    /// out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use extremedb::sql::engine::Engine;
+    /// # use extremedb::{connection, database, runtime, sql};
+    /// # use extremedb::device::util;
+    /// # fn main() -> extremedb::Result<()> {
+    /// #     let runtime = runtime::Runtime::start(vec![]);
+    /// #     let mut db_params = database::Params::new();
+    /// #     db_params
+    /// #         .ddl_dict_size(32768)
+    /// #         .max_classes(100)
+    /// #         .max_indexes(1000);
+    /// #     let mut devs = util::DeviceContainer::new();
+    /// #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+    /// #     let conn = connection::Connection::new(&db)?;
+    /// #     let engine = sql::engine::LocalEngine::new(&conn)?;
+    ///     engine.execute_statement("CREATE TABLE TestTable(a array(int));", &[])?;
+    ///
+    ///     let array: &[u32] = &[1, 2, 3];
+    ///     engine.execute_statement("INSERT INTO TestTable(a) VALUES(?);", &[&array])?;
+    ///
+    ///     let ds = engine.execute_query("SELECT a FROM TestTable;", &[])?.unwrap();
+    ///     let mut cur = ds.cursor()?;
+    ///     assert!(cur.advance()?);
+    ///
+    ///     let rec = cur.current_record().unwrap();
+    ///     let val = rec.get_at(0)?;
+    ///     let a = val.as_array()?;
+    ///
+    ///     assert_eq!(a.get(2)?.unwrap().to_i64()?, 3);
+    ///     assert!(a.get(3)?.is_none());
+    ///     assert!(a.get(100)?.is_none());
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn get(&self, at: usize) -> Result<Option<Ref<'_>>> {
+        if at < self.len()? {
+            self.get_at(at).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Returns an iterator for the array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use extremedb::sql::engine::Engine;
+    /// # use extremedb::{connection, database, runtime, sql};
+    /// # use extremedb::device::util;
+    /// # fn main() -> extremedb::Result<()> {
+    /// #     let runtime = runtime::Runtime::start(vec![]);
+    /// #     let mut db_params = database::Params::new();
+    /// #     db_params
+    /// #         .ddl_dict_size(32768)
+    /// #         .max_classes(100)
+    /// #         .max_indexes(1000);
+    /// #     let mut devs = util::DeviceContainer::new();
+    /// #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+    /// #     let conn = connection::Connection::new(&db)?;
+    /// #     let engine = sql::engine::LocalEngine::new(&conn)?;
+    ///     engine.execute_statement("CREATE TABLE TestTable(a array(int));", &[])?;
+    ///
+    ///     let array: &[u32] = &[5, 3, 8, 1];
+    ///     engine.execute_statement("INSERT INTO TestTable(a) VALUES(?);", &[&array])?;
+    ///
+    ///     let ds = engine.execute_query("SELECT a FROM TestTable;", &[])?.unwrap();
+    ///     let mut cur = ds.cursor()?;
+    ///     assert!(cur.advance()?);
+    ///
+    ///     let rec = cur.current_record().unwrap();
+    ///     let val = rec.get_at(0)?;
+    ///     let a = val.as_array()?;
+    ///
+    ///     let mut items = Vec::new();
+    ///     let mut it = a.iter();
+    ///     while it.advance()? {
+    ///         items.push(it.current_value().unwrap().to_i64()?);
+    ///     }
+    ///
+    ///     assert_eq!(items.len(), a.len()?);
+    ///     assert_eq!(items, vec![5, 3, 8, 1]);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn iter(&'a self) -> ArrayIterator<'a> {
+        ArrayIterator::new(self)
+    }
+
+    /// Collects the elements of the array into a vector.
+    ///
+    /// The element type of the array must match the SQL type of `T`
+    /// exactly, as reported by [`elem_type()`]; otherwise, an
+    /// `INVALID_TYPE_CAST` error is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use extremedb::sql::engine::Engine;
+    /// # use extremedb::{connection, database, runtime, sql};
+    /// # use extremedb::device::util;
+    /// # fn main() -> extremedb::Result<()> {
+    /// #     let runtime = runtime::Runtime::start(vec![]);
+    /// #     let mut db_params = database::Params::new();
+    /// #     db_params
+    /// #         .ddl_dict_size(32768)
+    /// #         .max_classes(100)
+    /// #         .max_indexes(1000);
+    /// #     let mut devs = util::DeviceContainer::new();
+    /// #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+    /// #     let conn = connection::Connection::new(&db)?;
+    /// #     let engine = sql::engine::LocalEngine::new(&conn)?;
+    ///     engine.execute_statement("CREATE TABLE TestTable(a array(int));", &[])?;
+    ///
+    ///     let array: &[i32] = &[7, -2, 40];
+    ///     engine.execute_statement("INSERT INTO TestTable(a) VALUES(?);", &[&array])?;
+    ///
+    ///     let ds = engine.execute_query("SELECT a FROM TestTable;", &[])?.unwrap();
+    ///     let mut cur = ds.cursor()?;
+    ///     assert!(cur.advance()?);
+    ///
+    ///     let rec = cur.current_record().unwrap();
+    ///     let val = rec.get_at(0)?;
+    ///     let a = val.as_array()?;
+    ///
+    ///     assert_eq!(a.to_vec::<i32>()?, vec![7, -2, 40]);
+    ///     assert!(a.to_vec::<f64>().is_err());
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`elem_type()`]: #method.elem_type
+    pub fn to_vec<T: FromValue + StaticTypeInfo>(&self) -> Result<Vec<T>> {
+        if self.elem_type()? != T::static_type() {
+            return Err(Error::new_sql(mcosql_error_code::INVALID_TYPE_CAST));
+        }
+
+        let len = self.len()?;
+        let mut ret = Vec::with_capacity(len);
+
+        for i in 0..len {
+            let elem = self.get_at(i)?;
+            ret.push(T::from_value(&elem)?);
+        }
+
+        Ok(ret)
+    }
+
+    /// The maximum number of elements rendered by [`join()`].
+    ///
+    /// [`join()`]: #method.join
+    pub const JOIN_LIMIT: usize = 100;
+
+    /// Renders the elements of the array as a single string, separated
+    /// by `sep`.
+    ///
+    /// Each element is converted using [`Value::to_string()`]. To keep the
+    /// output readable, at most [`JOIN_LIMIT`] elements are rendered; if the
+    /// array is longer, the result ends with `sep` followed by an ellipsis
+    /// (`...`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use extremedb::sql::engine::Engine;
+    /// # use extremedb::{connection, database, runtime, sql};
+    /// # use extremedb::device::util;
+    /// # fn main() -> extremedb::Result<()> {
+    /// #     let runtime = runtime::Runtime::start(vec![]);
+    /// #     let mut db_params = database::Params::new();
+    /// #     db_params
+    /// #         .ddl_dict_size(32768)
+    /// #         .max_classes(100)
+    /// #         .max_indexes(1000);
+    /// #     let mut devs = util::DeviceContainer::new();
+    /// #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+    /// #     let conn = connection::Connection::new(&db)?;
+    /// #     let engine = sql::engine::LocalEngine::new(&conn)?;
+    ///     engine.execute_statement("CREATE TABLE TestTable(a array(int), s array(string));", &[])?;
+    ///
+    ///     let ints: &[i32] = &[1, 2, 3];
+    ///     let strings: &[&str] = &["red", "green", "blue"];
+    ///     engine.execute_statement("INSERT INTO TestTable(a, s) VALUES(?, ?);", &[&ints, &strings])?;
+    ///
+    ///     let ds = engine.execute_query("SELECT a, s FROM TestTable;", &[])?.unwrap();
+    ///     let mut cur = ds.cursor()?;
+    ///     assert!(cur.advance()?);
+    ///
+    ///     let rec = cur.current_record().unwrap();
+    ///     let a = rec.get_at(0)?;
+    ///     let s = rec.get_at(1)?;
+    ///
+    ///     assert_eq!(a.as_array()?.join(", ")?, "1, 2, 3");
+    ///     assert_eq!(s.as_array()?.join(" | ")?, "red | green | blue");
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Value::to_string()`]: ./struct.Value.html#method.to_string
+    /// [`JOIN_LIMIT`]: #associatedconstant.JOIN_LIMIT
+    pub fn join(&self, sep: &str) -> Result<String> {
+        let len = self.len()?;
+        let mut ret = String::new();
+
+        for i in 0..len.min(Self::JOIN_LIMIT) {
+            if i > 0 {
+                ret.push_str(sep);
+            }
+            ret.push_str(&self.get_at(i)?.to_string()?);
+        }
+
+        if len > Self::JOIN_LIMIT {
+            ret.push_str(sep);
+            ret.push_str("...");
+        }
+
+        Ok(ret)
+    }
+
+    /// Collects the elements of a timestamp array into a vector of
+    /// `std::time::SystemTime` values.
+    ///
+    /// The element type of the array must be `Type::Time`; otherwise, an
+    /// `INVALID_TYPE_CAST` error is returned. The elements are converted
+    /// like in [`Value::to_system_time()`], but the runtime's datetime
+    /// precision is only queried once.
+    ///
+    /// If an element cannot be represented as `SystemTime`, an
+    /// `INVALID_TYPE_CAST` error is returned. The error does not carry the
+    /// index of the offending element; use [`iter()`] and
+    /// [`Value::to_system_time()`] to locate it, if necessary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use extremedb::sql::engine::Engine;
+    /// # use extremedb::{connection, database, runtime, sql};
+    /// # use extremedb::device::util;
+    /// # use std::time::{Duration, UNIX_EPOCH};
+    /// # fn main() -> extremedb::Result<()> {
+    /// #     let runtime = runtime::Runtime::start(vec![]);
+    /// #     let mut db_params = database::Params::new();
+    /// #     db_params
+    /// #         .ddl_dict_size(32768)
+    /// #         .max_classes(100)
+    /// #         .max_indexes(1000);
+    /// #     let mut devs = util::DeviceContainer::new();
+    /// #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+    /// #     let conn = connection::Connection::new(&db)?;
+    /// #     let engine = sql::engine::LocalEngine::new(&conn)?;
+    ///     engine.execute_statement("CREATE TABLE TestTable(a array(timestamp));", &[])?;
+    ///
+    ///     let times = vec![
+    ///         UNIX_EPOCH + Duration::from_secs(1_600_000_000),
+    ///         UNIX_EPOCH + Duration::from_secs(1_600_000_060),
+    ///         UNIX_EPOCH + Duration::from_secs(1_600_003_600),
+    ///     ];
+    ///     let array: &[_] = &times;
+    ///     engine.execute_statement("INSERT INTO TestTable(a) VALUES(?);", &[&array])?;
+    ///
+    ///     let ds = engine.execute_query("SELECT a FROM TestTable;", &[])?.unwrap();
+    ///     let mut cur = ds.cursor()?;
+    ///     assert!(cur.advance()?);
+    ///
+    ///     let rec = cur.current_record().unwrap();
+    ///     let val = rec.get_at(0)?;
+    ///     let a = val.as_array()?;
+    ///
+    ///     assert_eq!(a.to_system_time_vec()?, times);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Value::to_system_time()`]: ./struct.Value.html#method.to_system_time
+    /// [`iter()`]: #method.iter
+    pub fn to_system_time_vec(&self) -> Result<Vec<SystemTime>> {
+        if self.elem_type()? != Type::Time {
+            return Err(Error::new_sql(mcosql_error_code::INVALID_TYPE_CAST));
+        }
+
+        let prec = Value::datetime_precision();
+        let len = self.len()?;
+        let mut ret = Vec::with_capacity(len);
+
+        for i in 0..len {
+            let elem = self.get_at(i)?;
+            ret.push(Value::ticks_to_system_time(elem.to_date_time()?, prec)?);
+        }
+
+        Ok(ret)
+    }
+
     fn allocator(&'a self) -> Result<AllocatorRef<'a>> {
         let mut alloc_h = MaybeUninit::uninit();
 
@@ -1236,46 +3137,313 @@ impl<'a> TryFrom<Value<'a>> for Array<'a> {
 /// supported by the *e*X*treme*DB SQL engine. Any type that implements this
 /// trait can be passed as a parameter to the SQL statement execution methods.
 ///
+/// `ToValue` is also implemented for references to the types which implement
+/// it, so that the references to owned values can be passed as parameters
+/// directly.
+///
+/// Byte vectors and arrays (e.g. `Vec<u8>`) deliberately do not implement
+/// `ToValue`, so their references, such as `&Vec<u8>`, cannot be passed as
+/// parameters either. They must be wrapped with [`Binary::new()`] to be
+/// passed as binary values.
+///
+/// # Examples
+///
+/// ```
+/// # use extremedb::sql::engine::Engine;
+/// # use extremedb::sql::value::{Binary, Numeric};
+/// # use extremedb::{connection, database, device, runtime, sql};
+/// # use extremedb::device::util;
+/// # use std::time::SystemTime;
+/// # fn main() -> extremedb::Result<()> {
+/// #     let runtime = runtime::Runtime::start(vec![]);
+/// #     let mut db_params = database::Params::new();
+/// #     db_params
+/// #         .ddl_dict_size(32768)
+/// #         .max_classes(100)
+/// #         .max_indexes(1000);
+/// #     let mut devs = util::DeviceContainer::new();
+/// #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+/// #     let conn = connection::Connection::new(&db)?;
+/// #     let engine = sql::engine::LocalEngine::new(&conn)?;
+///     engine.execute_statement(
+///         "CREATE TABLE TestTable(n numeric(10, 2), t timestamp, s string, b varbinary);",
+///         &[],
+///     )?;
+///
+///     let num = Numeric::new(12345, 2).unwrap();
+///     let time = SystemTime::UNIX_EPOCH;
+///     let s = String::from("Hello");
+///     let bytes: Vec<u8> = vec![0x48, 0x69];
+///
+///     let num_ref = &num;
+///     let time_ref = &time;
+///     let s_ref = &s.as_str();
+///
+///     // `&bytes` would not compile here.
+///     engine.execute_statement(
+///         "INSERT INTO TestTable(n, t, s, b) VALUES(?, ?, ?, ?);",
+///         &[&num_ref, &time_ref, &s_ref, &Binary::new(&bytes)],
+///     )?;
+///
+///     let ds = engine.execute_query("SELECT n, t, s, b FROM TestTable;", &[])?.unwrap();
+///     let mut cur = ds.cursor()?;
+///     assert!(cur.advance()?);
+///
+///     let rec = cur.current_record().unwrap();
+///     assert_eq!(rec.get_at(0)?.to_numeric()?.value_scaled(), 12345);
+///     assert_eq!(rec.get_at(1)?.to_system_time()?, time);
+///     assert_eq!(rec.get_at(2)?.to_string()?, s);
+///     assert_eq!(rec.get_at(3)?.as_bytes()?, &bytes[..]);
+/// #     Ok(())
+/// # }
+/// ```
+///
 /// [`Value`]: ./trait.ToValue.html
+/// [`Binary::new()`]: ./struct.Binary.html#method.new
 pub trait ToValue {
     #[doc(hidden)]
     fn to_value<'a>(&self, alloc: AllocatorRef<'a>) -> Result<Value<'a>>;
 }
 
-/// An SQL sequence.
+/// A trait for converting a SQL [`Value`] to a native Rust type.
 ///
-/// An *e*X*treme*DB SQL sequence contains [`Value`]s of the same type, and
-/// exposes public methods to access them.
-///
-/// Sequences are conceptually different from arrays. Because of a different
-/// internal implementation, their elements are accessed using an iterator,
-/// instead of the getter methods.
-///
-/// A [`Value`] can only be converted into a `Sequence` if it has the `Sequence`
-/// type. The opposite conversion is always possible.
+/// This module implements the `FromValue` trait for the common Rust types
+/// supported by the *e*X*treme*DB SQL engine. The conversions follow the
+/// rules of the corresponding [`Value`] methods; integer conversions fail
+/// if the value is out of the target type's range.
 ///
 /// [`Value`]: ./struct.Value.html
-// WARNING: must have same repr as Value! Value is cast to Sequence in Value::as_sequence
-#[repr(transparent)]
-pub struct Sequence<'a> {
-    val: Value<'a>,
+pub trait FromValue: Sized {
+    /// Converts the value to `Self`.
+    fn from_value(val: &Value) -> Result<Self>;
 }
 
-impl<'a> Sequence<'a> {
-    /// Returns the type of the sequence elements.
-    pub fn elem_type(&self) -> Result<Type> {
-        let mut ty = MaybeUninit::uninit();
+macro_rules! impl_from_value_int {
+    ($ty:ty) => {
+        impl FromValue for $ty {
+            fn from_value(val: &Value) -> Result<Self> {
+                <$ty>::try_from(val.to_i64()?)
+                    .or(Err(Error::new_sql(mcosql_error_code::INVALID_TYPE_CAST)))
+            }
+        }
+    };
+}
 
-        result_from_code(unsafe { exdb_sys::mcosql_rs_seq_elem_type(self.val.h, ty.as_mut_ptr()) })
-            .and(
-                Type::from_mco(unsafe { ty.assume_init() })
-                    .ok_or(Error::new_sql(mcosql_error_code::RUNTIME_ERROR)),
-            )
-    }
+impl_from_value_int!(u8);
+impl_from_value_int!(u16);
+impl_from_value_int!(u32);
+impl_from_value_int!(i8);
+impl_from_value_int!(i16);
+impl_from_value_int!(i32);
 
-    /// Returns the number of elements in the sequence.
-    pub fn count(&self) -> Result<usize> {
-        let mut ret = MaybeUninit::uninit();
+impl FromValue for u64 {
+    fn from_value(val: &Value) -> Result<Self> {
+        val.to_u64()
+    }
+}
+
+impl FromValue for i64 {
+    fn from_value(val: &Value) -> Result<Self> {
+        val.to_i64()
+    }
+}
+
+impl FromValue for f32 {
+    fn from_value(val: &Value) -> Result<Self> {
+        val.to_real().map(|v| v as f32)
+    }
+}
+
+impl FromValue for f64 {
+    fn from_value(val: &Value) -> Result<Self> {
+        val.to_real()
+    }
+}
+
+impl FromValue for bool {
+    fn from_value(val: &Value) -> Result<Self> {
+        val.to_bool()
+    }
+}
+
+impl FromValue for String {
+    fn from_value(val: &Value) -> Result<Self> {
+        val.to_string()
+    }
+}
+
+impl FromValue for Vec<u8> {
+    fn from_value(val: &Value) -> Result<Self> {
+        val.to_binary()
+    }
+}
+
+impl FromValue for SystemTime {
+    fn from_value(val: &Value) -> Result<Self> {
+        val.to_system_time()
+    }
+}
+
+impl FromValue for Numeric {
+    fn from_value(val: &Value) -> Result<Self> {
+        val.to_numeric()
+    }
+}
+
+impl<T: FromValue> FromValue for Option<T> {
+    fn from_value(val: &Value) -> Result<Self> {
+        if val.is_null() {
+            Ok(None)
+        } else {
+            T::from_value(val).map(Some)
+        }
+    }
+}
+
+/// A value detached from the SQL engine's allocator.
+///
+/// Unlike [`Value`]s and [`Ref`]s, owned values do not borrow the records
+/// they were read from, and can be kept after the cursor is advanced or
+/// the data source is released.
+///
+/// All signed integer types are stored as `i64`, all unsigned integer types
+/// as `u64`, and all floating-point types as `f64`. Blobs, sequences, lists
+/// and nested data sources cannot be detached; converting them fails with
+/// an `INVALID_TYPE_CAST` error.
+///
+/// # Examples
+///
+/// ```
+/// # use extremedb::sql::engine::Engine;
+/// # use extremedb::sql::value::OwnedValue;
+/// # use extremedb::{connection, database, runtime, sql};
+/// # use extremedb::device::util;
+/// # fn main() -> extremedb::Result<()> {
+/// #     let runtime = runtime::Runtime::start(vec![]);
+/// #     let mut db_params = database::Params::new();
+/// #     db_params
+/// #         .ddl_dict_size(32768)
+/// #         .max_classes(100)
+/// #         .max_indexes(1000);
+/// #     let mut devs = util::DeviceContainer::new();
+/// #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+/// #     let conn = connection::Connection::new(&db)?;
+/// #     let engine = sql::engine::LocalEngine::new(&conn)?;
+///     engine.execute_statement("CREATE TABLE TestTable(u unsigned(8), i bigint);", &[])?;
+///     engine.execute_statement(
+///         "INSERT INTO TestTable(u, i) VALUES(?, ?);",
+///         &[&u64::MAX, &i64::MIN],
+///     )?;
+///
+///     let ds = engine.execute_query("SELECT u, i FROM TestTable;", &[])?.unwrap();
+///     let mut cur = ds.cursor()?;
+///     assert!(cur.advance()?);
+///
+///     let row = cur.current_record().unwrap().to_owned_row()?;
+///     assert!(matches!(row[0], OwnedValue::UInt(u64::MAX)));
+///     assert!(matches!(row[1], OwnedValue::Int(i64::MIN)));
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [`Value`]: ./struct.Value.html
+/// [`Ref`]: ./struct.Ref.html
+#[derive(Clone, Debug)]
+pub enum OwnedValue {
+    /// A `null` value.
+    Null,
+
+    /// A boolean value.
+    Bool(bool),
+
+    /// A signed integer value.
+    Int(i64),
+
+    /// An unsigned integer value.
+    UInt(u64),
+
+    /// A floating-point value.
+    Real(f64),
+
+    /// A timestamp, in system ticks.
+    DateTime(Ticks),
+
+    /// A fixed-width numeric value.
+    Numeric(Numeric),
+
+    /// A string.
+    String(String),
+
+    /// A byte array.
+    Binary(Vec<u8>),
+
+    /// An array of values.
+    Array(Vec<OwnedValue>),
+}
+
+impl FromValue for OwnedValue {
+    fn from_value(val: &Value) -> Result<Self> {
+        match val.value_type()? {
+            Type::Null => Ok(OwnedValue::Null),
+            Type::Bool => Ok(OwnedValue::Bool(val.is_true())),
+            Type::Int1 | Type::Int2 | Type::Int4 | Type::Int8 => val.to_i64().map(OwnedValue::Int),
+            Type::UInt1 | Type::UInt2 | Type::UInt4 | Type::UInt8 => {
+                val.to_u64().map(OwnedValue::UInt)
+            }
+            Type::Real4 | Type::Real8 => val.to_real().map(OwnedValue::Real),
+            Type::Time => val.to_date_time().map(|t| OwnedValue::DateTime(Ticks(t))),
+            Type::Numeric => val.to_numeric().map(OwnedValue::Numeric),
+            Type::String => val.to_string().map(OwnedValue::String),
+            Type::Binary => val.to_binary().map(OwnedValue::Binary),
+            Type::Array => {
+                let array = val.as_array()?;
+                let mut elems = Vec::with_capacity(array.len()?);
+                for i in 0..array.len()? {
+                    elems.push(OwnedValue::from_value(&*array.get_at(i)?)?);
+                }
+                Ok(OwnedValue::Array(elems))
+            }
+            Type::Blob | Type::DataSource | Type::List | Type::Sequence => {
+                Err(Error::new_sql(mcosql_error_code::INVALID_TYPE_CAST))
+            }
+        }
+    }
+}
+
+/// An SQL sequence.
+///
+/// An *e*X*treme*DB SQL sequence contains [`Value`]s of the same type, and
+/// exposes public methods to access them.
+///
+/// Sequences are conceptually different from arrays. Because of a different
+/// internal implementation, their elements are accessed using an iterator,
+/// instead of the getter methods.
+///
+/// A [`Value`] can only be converted into a `Sequence` if it has the `Sequence`
+/// type. The opposite conversion is always possible.
+///
+/// [`Value`]: ./struct.Value.html
+// WARNING: must have same repr as Value! Value is cast to Sequence in Value::as_sequence
+#[repr(transparent)]
+pub struct Sequence<'a> {
+    val: Value<'a>,
+}
+
+impl<'a> Sequence<'a> {
+    /// Returns the type of the sequence elements.
+    pub fn elem_type(&self) -> Result<Type> {
+        let mut ty = MaybeUninit::uninit();
+
+        result_from_code(unsafe { exdb_sys::mcosql_rs_seq_elem_type(self.val.h, ty.as_mut_ptr()) })
+            .and(
+                Type::from_mco(unsafe { ty.assume_init() })
+                    .ok_or(Error::new_sql(mcosql_error_code::RUNTIME_ERROR)),
+            )
+    }
+
+    /// Returns the number of elements in the sequence.
+    pub fn count(&self) -> Result<usize> {
+        let mut ret = MaybeUninit::uninit();
 
         result_from_code(unsafe { exdb_sys::mcosql_rs_seq_count(self.val.h, ret.as_mut_ptr()) })
             .and(Ok(unsafe { ret.assume_init() } as usize))
@@ -1288,6 +3456,71 @@ impl<'a> Sequence<'a> {
             .and(Ok(SequenceIterator::new(self)))
     }
 
+    /// Collects the elements of the sequence into a vector.
+    ///
+    /// The element type of the sequence must match the SQL type of `T`
+    /// exactly, as reported by [`elem_type()`]; otherwise, an
+    /// `INVALID_TYPE_CAST` error is returned. The elements are collected in
+    /// the iteration order, using a new [`iterator()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use extremedb::sql::engine::Engine;
+    /// # use extremedb::{connection, database, runtime, sql};
+    /// # use extremedb::device::util;
+    /// # fn main() -> extremedb::Result<()> {
+    /// #     let runtime = runtime::Runtime::start(vec![]);
+    /// #     let mut db_params = database::Params::new();
+    /// #     db_params
+    /// #         .ddl_dict_size(32768)
+    /// #         .max_classes(100)
+    /// #         .max_indexes(1000);
+    /// #     let mut devs = util::DeviceContainer::new();
+    /// #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+    /// #     let conn = connection::Connection::new(&db)?;
+    /// #     let engine = sql::engine::LocalEngine::new(&conn)?;
+    ///     engine.execute_statement(
+    ///         "CREATE TABLE TestTable(id integer primary key, s sequence(int8));",
+    ///         &[],
+    ///     )?;
+    ///     engine.execute_statement("INSERT INTO TestTable VALUES(1, [30, 10, 20, 40]);", &[])?;
+    ///
+    ///     let ds = engine.execute_query("SELECT s FROM TestTable;", &[])?.unwrap();
+    ///     let mut cur = ds.cursor()?;
+    ///     assert!(cur.advance()?);
+    ///
+    ///     let rec = cur.current_record().unwrap();
+    ///     let val = rec.get_at(0)?;
+    ///     let seq = val.as_sequence()?;
+    ///
+    ///     let items = seq.to_vec::<i64>()?;
+    ///     assert_eq!(items, vec![30, 10, 20, 40]);
+    ///     assert_eq!(items.len(), seq.count()?);
+    ///     assert!(seq.to_vec::<f64>().is_err());
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`elem_type()`]: #method.elem_type
+    /// [`iterator()`]: #method.iterator
+    pub fn to_vec<T: FromValue + StaticTypeInfo>(&'a self) -> Result<Vec<T>> {
+        if self.elem_type()? != T::static_type() {
+            return Err(Error::new_sql(mcosql_error_code::INVALID_TYPE_CAST));
+        }
+
+        let mut ret = Vec::with_capacity(self.count()?);
+        let mut it = self.iterator()?;
+
+        while it.advance()? {
+            if let Some(elem) = it.current_value() {
+                ret.push(T::from_value(&elem)?);
+            }
+        }
+
+        Ok(ret)
+    }
+
     fn get_iterator(&self) -> Result<()> {
         result_from_code(unsafe { exdb_sys::mcosql_rs_seq_get_iterator(self.val.h) })
     }
@@ -1316,6 +3549,57 @@ impl<'a> Sequence<'a> {
     }
 }
 
+/// An array iterator.
+///
+/// This type is used to iterate through the elements of the array. Like the
+/// [`SequenceIterator`] type, it does not follow the conventions of the
+/// standard Rust iterators, because lifetimes of the values it returns are
+/// constrained by the lifetime of the array.
+///
+/// The iterator is initially positioned before the first element.
+///
+/// [`SequenceIterator`]: ./struct.SequenceIterator.html
+pub struct ArrayIterator<'a> {
+    array: &'a Array<'a>,
+    next: usize,
+    val_ref: Option<Ref<'a>>,
+}
+
+impl<'a> ArrayIterator<'a> {
+    fn new(array: &'a Array<'a>) -> Self {
+        ArrayIterator {
+            array,
+            next: 0,
+            val_ref: None,
+        }
+    }
+
+    /// Advances the iterator.
+    ///
+    /// If this function returns `true`, the current element can be accessed.
+    /// `false` indicates that the iterator has been moved past the last
+    /// element.
+    pub fn advance(&mut self) -> Result<bool> {
+        self.val_ref = self.array.get(self.next)?;
+
+        if self.val_ref.is_some() {
+            self.next += 1;
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+
+    /// Returns the element currently pointed at by the iterator.
+    ///
+    /// Returns `None` if the iterator hasn't been advanced at least once,
+    /// or has reached the end of the array.
+    pub fn current_value(&'a self) -> Option<Ref<'a>> {
+        // Produce a defused reference
+        self.val_ref.as_ref().map(|r| r.defused_clone())
+    }
+}
+
 /// A sequence iterator.
 ///
 /// This type is used to iterate through the items of the sequence. Like the
@@ -1370,12 +3654,201 @@ impl<'a> SequenceIterator<'a> {
             Some(self.val_ref.defused_clone())
         }
     }
+
+    /// Converts this iterator into a standard Rust iterator over the
+    /// copies of the remaining elements.
+    ///
+    /// Each element is copied using the [`FromValue`] implementation
+    /// of `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use extremedb::sql::engine::Engine;
+    /// # use extremedb::{connection, database, runtime, sql};
+    /// # use extremedb::device::util;
+    /// # fn main() -> extremedb::Result<()> {
+    /// #     let runtime = runtime::Runtime::start(vec![]);
+    /// #     let mut db_params = database::Params::new();
+    /// #     db_params
+    /// #         .ddl_dict_size(32768)
+    /// #         .max_classes(100)
+    /// #         .max_indexes(1000);
+    /// #     let mut devs = util::DeviceContainer::new();
+    /// #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+    /// #     let conn = connection::Connection::new(&db)?;
+    /// #     let engine = sql::engine::LocalEngine::new(&conn)?;
+    ///     engine.execute_statement(
+    ///         "CREATE TABLE TestTable(id integer primary key, s sequence(int8));",
+    ///         &[],
+    ///     )?;
+    ///     engine.execute_statement("INSERT INTO TestTable VALUES(1, [1, 2, 3, 4]);", &[])?;
+    ///
+    ///     let ds = engine.execute_query("SELECT s FROM TestTable;", &[])?.unwrap();
+    ///     let mut cur = ds.cursor()?;
+    ///     assert!(cur.advance()?);
+    ///
+    ///     let rec = cur.current_record().unwrap();
+    ///     let val = rec.get_at(0)?;
+    ///     let seq = val.as_sequence()?;
+    ///
+    ///     let sum_of_squares = seq
+    ///         .iterator()?
+    ///         .values::<i64>()
+    ///         .map(|v| v.map(|v| v * v))
+    ///         .sum::<extremedb::Result<i64>>()?;
+    ///     assert_eq!(sum_of_squares, 30);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`FromValue`]: ./trait.FromValue.html
+    pub fn values<T: FromValue>(self) -> SequenceValues<'a, T> {
+        SequenceValues {
+            it: self,
+            elem: PhantomData,
+        }
+    }
+}
+
+/// An iterator over the copies of the sequence elements.
+///
+/// This type is returned by [`SequenceIterator::values()`]. Unlike the
+/// `SequenceIterator`, it implements the standard `Iterator` trait, which
+/// allows using it in `for` loops and with the iterator adapters. This is
+/// only possible because every element is *copied* into a value of type
+/// `T`, which does not borrow the sequence; the copying can be noticeably
+/// slower than accessing the elements with [`SequenceIterator`] for large
+/// sequences.
+///
+/// Each item is a `Result`: an error is produced if the sequence cannot be
+/// advanced, or the element cannot be converted to `T`.
+///
+/// [`SequenceIterator`]: ./struct.SequenceIterator.html
+/// [`SequenceIterator::values()`]: ./struct.SequenceIterator.html#method.values
+pub struct SequenceValues<'a, T> {
+    it: SequenceIterator<'a>,
+    elem: PhantomData<T>,
+}
+
+impl<'a, T: FromValue> Iterator for SequenceValues<'a, T> {
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.it.advance() {
+            Ok(true) => self.it.current_value().map(|v| T::from_value(&v)),
+            Ok(false) => None,
+            Err(e) => Some(Err(e)),
+        }
+    }
 }
 
 /// A fixed-width integer.
 ///
 /// This type is used to pass fixed-width integers between the application code
 /// and the SQL engine.
+///
+/// # Examples
+///
+/// Numeric values are displayed with exactly `precision` fractional digits:
+///
+/// ```
+/// # use extremedb::sql::value::Numeric;
+/// assert_eq!(Numeric::new(12005, 3).unwrap().to_string(), "12.005");
+/// assert_eq!(Numeric::new(-5, 3).unwrap().to_string(), "-0.005");
+/// assert_eq!(Numeric::new(-12005, 3).unwrap().to_string(), "-12.005");
+/// assert_eq!(Numeric::new(100, 2).unwrap().to_string(), "1.00");
+/// assert_eq!(Numeric::from_int(42).to_string(), "42");
+/// ```
+///
+/// Decimal strings can be parsed into numeric values; the precision is
+/// inferred from the number of fractional digits:
+///
+/// ```
+/// # use extremedb::sql::value::Numeric;
+/// let num: Numeric = "12.345".parse().unwrap();
+/// assert_eq!(num.destruct(), (12345, 3));
+///
+/// let num: Numeric = "-0.01".parse().unwrap();
+/// assert_eq!(num.destruct(), (-1, 2));
+///
+/// let num: Numeric = "42".parse().unwrap();
+/// assert_eq!(num.destruct(), (42, 0));
+///
+/// assert!("12.3.4".parse::<Numeric>().is_err());
+/// assert!("12.".parse::<Numeric>().is_err());
+/// assert!("abc".parse::<Numeric>().is_err());
+/// assert!("0.12345678901234567890".parse::<Numeric>().is_err());
+/// ```
+///
+/// Formatting and parsing round-trip for all the precisions and values:
+///
+/// ```
+/// # use extremedb::sql::value::Numeric;
+/// for s in &[
+///     "0.9000000000000000000",
+///     "-0.9000000000000000000",
+///     "-0.9223372036854775808",
+///     "-922337203685477580.8",
+///     "-9223372036854775808",
+/// ] {
+///     assert_eq!(s.parse::<Numeric>().unwrap().to_string(), *s);
+/// }
+///
+/// let min = Numeric::new(i64::MIN, 19).unwrap();
+/// assert_eq!(min.int_part(), 0);
+/// assert_eq!(min.fract_part(), 9223372036854775808);
+/// assert_eq!(min.to_string().parse::<Numeric>().unwrap().destruct(), (i64::MIN, 19));
+/// ```
+///
+/// Numeric values can be added and subtracted without converting them to
+/// floating-point numbers. The operands are rescaled to the greater of the
+/// two precisions. Like [`checked_add()`] and [`checked_sub()`], the
+/// operators return `None` on overflow. Multiplication and division are not
+/// currently supported.
+///
+/// ```
+/// # use extremedb::sql::value::Numeric;
+/// let a = Numeric::new(12345, 3).unwrap();
+/// let b = Numeric::new(100, 2).unwrap();
+///
+/// assert_eq!((a + b).unwrap().destruct(), (13345, 3));
+/// assert_eq!((a - b).unwrap().destruct(), (11345, 3));
+/// assert_eq!((b - a).unwrap().destruct(), (-11345, 3));
+/// assert_eq!((-a).unwrap().destruct(), (-12345, 3));
+///
+/// let max = Numeric::from_int(i64::MAX);
+/// assert!((max + Numeric::from_int(1)).is_none());
+/// assert!(max.checked_add(b).is_none());
+/// assert!((-Numeric::from_int(i64::MIN)).is_none());
+/// ```
+///
+/// Numeric values are compared and hashed by the values they represent,
+/// regardless of their precisions. This makes it possible to use them as
+/// keys in the `HashMap`s and `BTreeMap`s:
+///
+/// ```
+/// # use extremedb::sql::value::Numeric;
+/// # use std::collections::HashMap;
+/// let a = Numeric::new(1200, 3).unwrap();
+/// let b = Numeric::new(12000, 4).unwrap();
+/// let c = Numeric::new(1201, 3).unwrap();
+///
+/// assert_eq!(a, b);
+/// assert!(a < c);
+///
+/// let mut prices = HashMap::new();
+/// prices.insert(a, "first");
+/// prices.insert(b, "second");
+/// prices.insert(c, "third");
+///
+/// assert_eq!(prices.len(), 2);
+/// assert_eq!(prices[&"1.2".parse().unwrap()], "second");
+/// ```
+///
+/// [`checked_add()`]: #method.checked_add
+/// [`checked_sub()`]: #method.checked_sub
+#[derive(Clone, Copy, Debug)]
 pub struct Numeric {
     val_scaled: i64,
     prec: usize,
@@ -1405,11 +3878,140 @@ impl Numeric {
         }
     }
 
+    /// Constructs a new fixed-width integer with zero precision from an
+    /// integer value.
+    ///
+    /// `From<i64>` and `From<i32>` are also implemented for `Numeric`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use extremedb::sql::value::Numeric;
+    /// let num = Numeric::from_int(42);
+    /// assert_eq!(num.int_part(), 42);
+    /// assert_eq!(num.fract_part(), 0);
+    /// assert_eq!(num.precision(), 0);
+    ///
+    /// let num = Numeric::from(42i32);
+    /// assert_eq!(num.value_scaled(), 42);
+    /// ```
+    ///
+    /// Integer-valued numerics can be bound to `numeric` columns:
+    ///
+    /// ```
+    /// # use extremedb::sql::engine::Engine;
+    /// # use extremedb::sql::value::Numeric;
+    /// # use extremedb::{connection, database, device, runtime, sql};
+    /// # use extremedb::device::util;
+    /// # fn main() -> extremedb::Result<()> {
+    /// #     let runtime = runtime::Runtime::start(vec![]);
+    /// #     let mut db_params = database::Params::new();
+    /// #     db_params
+    /// #         .ddl_dict_size(32768)
+    /// #         .max_classes(100)
+    /// #         .max_indexes(1000);
+    /// #     let mut devs = util::DeviceContainer::new();
+    /// #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+    /// #     let conn = connection::Connection::new(&db)?;
+    /// #     let engine = sql::engine::LocalEngine::new(&conn)?;
+    ///     engine.execute_statement("CREATE TABLE TestTable(n numeric(10, 0));", &[])?;
+    ///     engine.execute_statement(
+    ///         "INSERT INTO TestTable(n) VALUES(?);",
+    ///         &[&Numeric::from(42i64)],
+    ///     )?;
+    ///
+    ///     let ds = engine.execute_query("SELECT n FROM TestTable;", &[])?.unwrap();
+    ///     let mut cur = ds.cursor()?;
+    ///     assert!(cur.advance()?);
+    ///
+    ///     let rec = cur.current_record().unwrap();
+    ///     let num = rec.get_at(0)?.to_numeric()?;
+    ///     assert_eq!(num.int_part(), 42);
+    ///     assert_eq!(num.fract_part(), 0);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn from_int(val: i64) -> Self {
+        Numeric {
+            val_scaled: val,
+            prec: 0,
+        }
+    }
+
+    /// Constructs a new fixed-width integer from a floating-point value
+    /// and precision.
+    ///
+    /// The value is scaled by `10^prec` and rounded to the nearest integer,
+    /// with ties rounded to even. Note that the rounding applies to the
+    /// binary floating-point value, which may not represent the decimal
+    /// literal exactly.
+    ///
+    /// Returns `None` if precision is greater than 19, or the scaled value
+    /// does not fit in `i64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use extremedb::sql::value::Numeric;
+    /// let num = Numeric::from_f64(12.345, 2).unwrap();
+    /// assert_eq!(num.value_scaled(), 1234);
+    ///
+    /// // 1.005 is stored as 1.00499999999999989...
+    /// assert_eq!(Numeric::from_f64(1.005, 2).unwrap().value_scaled(), 100);
+    ///
+    /// // Ties are rounded to even.
+    /// assert_eq!(Numeric::from_f64(0.125, 2).unwrap().value_scaled(), 12);
+    /// assert_eq!(Numeric::from_f64(0.375, 2).unwrap().value_scaled(), 38);
+    /// assert_eq!(Numeric::from_f64(-0.125, 2).unwrap().value_scaled(), -12);
+    ///
+    /// assert_eq!(Numeric::from_f64(-2.5, 3).unwrap().value_scaled(), -2500);
+    ///
+    /// assert!(Numeric::from_f64(1e18, 2).is_none());
+    /// assert!(Numeric::from_f64(f64::NAN, 2).is_none());
+    /// assert!(Numeric::from_f64(1.0, 20).is_none());
+    /// ```
+    pub fn from_f64(val: f64, prec: usize) -> Option<Self> {
+        if prec > 19 {
+            return None;
+        }
+
+        let scaled = val * 10f64.powi(prec as i32);
+        let mut rounded = scaled.round();
+        if (rounded - scaled).abs() == 0.5 && rounded % 2.0 != 0.0 {
+            rounded -= scaled.signum();
+        }
+
+        // i64::MAX is not representable as f64; the bound is exclusive.
+        if rounded >= i64::MIN as f64 && rounded < i64::MAX as f64 {
+            Numeric::new(rounded as i64, prec)
+        } else {
+            None
+        }
+    }
+
     /// Returns the scaled value.
     pub fn value_scaled(&self) -> i64 {
         self.val_scaled
     }
 
+    /// Returns the value in minor units: the scaled value, interpreted at
+    /// the numeric's own precision.
+    ///
+    /// For example, a monetary amount with a precision of 2 is returned as
+    /// an integer number of cents. The values with different precisions
+    /// are not comparable in minor units.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use extremedb::sql::value::Numeric;
+    /// let price = Numeric::new(1999, 2).unwrap();
+    /// assert_eq!(price.as_minor_units(), 1999);
+    /// ```
+    pub fn as_minor_units(&self) -> i64 {
+        self.val_scaled
+    }
+
     /// Returns the precision.
     pub fn precision(&self) -> usize {
         self.prec
@@ -1417,12 +4019,85 @@ impl Numeric {
 
     /// Returns the integer part of the numeric value.
     pub fn int_part(&self) -> i64 {
-        self.val_scaled / self.scale() as i64
+        (self.val_scaled as i128 / self.scale()) as i64
     }
 
     /// Returns the fractional part of the numeric value.
     pub fn fract_part(&self) -> u64 {
-        (self.val_scaled.abs() as u64).wrapping_rem(self.scale() as u64)
+        (self.val_scaled as i128 % self.scale()).unsigned_abs() as u64
+    }
+
+    /// Converts this numeric value to a different precision.
+    ///
+    /// When the precision is reduced, the value is rounded to the nearest
+    /// representable value, with ties rounded to even. When the precision
+    /// is increased, the value is extended with zeroes.
+    ///
+    /// Returns `None` if `new_prec` is greater than 19, or the rescaled
+    /// value does not fit in `i64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use extremedb::sql::value::Numeric;
+    /// let num = Numeric::new(12345, 3).unwrap();
+    ///
+    /// assert_eq!(num.rescale(1).unwrap().destruct(), (123, 1));
+    /// assert_eq!(num.rescale(5).unwrap().destruct(), (1234500, 5));
+    /// assert_eq!(num.rescale(3).unwrap().destruct(), (12345, 3));
+    ///
+    /// // Ties are rounded to even.
+    /// assert_eq!(num.rescale(2).unwrap().destruct(), (1234, 2));
+    /// assert_eq!(Numeric::new(-12355, 3).unwrap().rescale(2).unwrap().destruct(), (-1236, 2));
+    ///
+    /// assert!(num.rescale(20).is_none());
+    /// assert!(Numeric::from_int(i64::MAX).rescale(1).is_none());
+    /// ```
+    pub fn rescale(&self, new_prec: usize) -> Option<Numeric> {
+        if new_prec > 19 {
+            return None;
+        }
+
+        if new_prec >= self.prec {
+            return Numeric::new(self.scaled_to(new_prec)?, new_prec);
+        }
+
+        let div = 10i128.pow((self.prec - new_prec) as u32);
+        let val = self.val_scaled as i128;
+        let mut quot = val / div;
+        let rem = (val % div).abs();
+
+        if rem * 2 > div || (rem * 2 == div && quot % 2 != 0) {
+            quot += val.signum();
+        }
+
+        Numeric::new(i64::try_from(quot).ok()?, new_prec)
+    }
+
+    /// Adds two numeric values, returning `None` on overflow.
+    ///
+    /// The result has the greater of the two precisions.
+    pub fn checked_add(self, rhs: Numeric) -> Option<Numeric> {
+        let prec = self.prec.max(rhs.prec);
+        let val_scaled = self.scaled_to(prec)?.checked_add(rhs.scaled_to(prec)?)?;
+        Some(Numeric { val_scaled, prec })
+    }
+
+    /// Subtracts `rhs` from this numeric value, returning `None` on overflow.
+    ///
+    /// The result has the greater of the two precisions.
+    pub fn checked_sub(self, rhs: Numeric) -> Option<Numeric> {
+        let prec = self.prec.max(rhs.prec);
+        let val_scaled = self.scaled_to(prec)?.checked_sub(rhs.scaled_to(prec)?)?;
+        Some(Numeric { val_scaled, prec })
+    }
+
+    /// Negates this numeric value, returning `None` on overflow.
+    pub fn checked_neg(self) -> Option<Numeric> {
+        Some(Numeric {
+            val_scaled: self.val_scaled.checked_neg()?,
+            prec: self.prec,
+        })
     }
 
     /// Destructures the numeric value into the scaled value and the precision.
@@ -1430,8 +4105,81 @@ impl Numeric {
         (self.val_scaled, self.prec)
     }
 
-    fn scale(&self) -> usize {
-        10usize.pow(self.prec as u32)
+    fn scale(&self) -> i128 {
+        10i128.pow(self.prec as u32)
+    }
+
+    // Returns the scaled value and the precision with the trailing
+    // fractional zeroes removed.
+    fn normalized(&self) -> (i64, usize) {
+        let (mut val, mut prec) = (self.val_scaled, self.prec);
+        while prec > 0 && val % 10 == 0 {
+            val /= 10;
+            prec -= 1;
+        }
+        (val, prec)
+    }
+
+    // Returns the scaled value at a precision which is not less than the
+    // current one.
+    fn scaled_to(&self, prec: usize) -> Option<i64> {
+        debug_assert!(prec >= self.prec);
+        // Both precisions are at most 19, so the product fits in i128.
+        let mul = 10i128.pow((prec - self.prec) as u32);
+        i64::try_from(self.val_scaled as i128 * mul).ok()
+    }
+}
+
+impl Add for Numeric {
+    type Output = Option<Numeric>;
+
+    fn add(self, rhs: Numeric) -> Option<Numeric> {
+        self.checked_add(rhs)
+    }
+}
+
+impl Sub for Numeric {
+    type Output = Option<Numeric>;
+
+    fn sub(self, rhs: Numeric) -> Option<Numeric> {
+        self.checked_sub(rhs)
+    }
+}
+
+impl Neg for Numeric {
+    type Output = Option<Numeric>;
+
+    fn neg(self) -> Option<Numeric> {
+        self.checked_neg()
+    }
+}
+
+impl PartialEq for Numeric {
+    fn eq(&self, other: &Numeric) -> bool {
+        self.normalized() == other.normalized()
+    }
+}
+
+impl Eq for Numeric {}
+
+impl PartialOrd for Numeric {
+    fn partial_cmp(&self, other: &Numeric) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Numeric {
+    fn cmp(&self, other: &Numeric) -> Ordering {
+        // Both precisions are at most 19, so the products fit in i128.
+        let prec = self.prec.max(other.prec);
+        let scaled = |n: &Numeric| n.val_scaled as i128 * 10i128.pow((prec - n.prec) as u32);
+        scaled(self).cmp(&scaled(other))
+    }
+}
+
+impl Hash for Numeric {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.normalized().hash(state)
     }
 }
 
@@ -1441,9 +4189,150 @@ impl Into<f64> for Numeric {
     }
 }
 
+impl From<i64> for Numeric {
+    fn from(val: i64) -> Self {
+        Numeric::from_int(val)
+    }
+}
+
+impl From<i32> for Numeric {
+    fn from(val: i32) -> Self {
+        Numeric::from_int(val as i64)
+    }
+}
+
+/// Numeric values can be converted to `rust_decimal::Decimal`s without loss
+/// of precision.
+///
+/// This conversion is only available when the `rust_decimal` feature is
+/// enabled.
+///
+/// # Examples
+///
+/// ```
+/// # use extremedb::sql::value::Numeric;
+/// # use rust_decimal::Decimal;
+/// let num = Numeric::new(-12345, 3).unwrap();
+/// let dec = Decimal::from(num);
+/// assert_eq!(dec.to_string(), "-12.345");
+/// ```
+#[cfg(feature = "rust_decimal")]
+impl From<Numeric> for Decimal {
+    fn from(num: Numeric) -> Self {
+        Decimal::new(num.val_scaled, num.prec as u32)
+    }
+}
+
+/// Converts a `rust_decimal::Decimal` to a numeric value.
+///
+/// The trailing fractional zeroes are removed if the decimal's scale
+/// exceeds the maximum precision of a numeric value. The conversion fails
+/// with an `INVALID_TYPE_CAST` error if the decimal still has too many
+/// fractional digits, or its scaled value does not fit in `i64`.
+///
+/// This conversion is only available when the `rust_decimal` feature is
+/// enabled.
+///
+/// # Examples
+///
+/// ```
+/// # use extremedb::sql::value::Numeric;
+/// # use rust_decimal::Decimal;
+/// # use std::convert::TryFrom;
+/// for s in &["0", "12.345", "-0.001", "9223372036854775807"] {
+///     let dec: Decimal = s.parse().unwrap();
+///     let num = Numeric::try_from(dec).unwrap();
+///     assert_eq!(num.to_string(), *s);
+///     assert_eq!(Decimal::from(num), dec);
+/// }
+///
+/// // Too large for a numeric value.
+/// assert!(Numeric::try_from(Decimal::from(i64::MAX) + Decimal::ONE).is_err());
+///
+/// // Too many fractional digits.
+/// let dec = Decimal::new(1, 20);
+/// assert!(Numeric::try_from(dec).is_err());
+/// ```
+#[cfg(feature = "rust_decimal")]
+impl TryFrom<Decimal> for Numeric {
+    type Error = Error;
+
+    fn try_from(dec: Decimal) -> Result<Self> {
+        let invalid = || Error::new_sql(mcosql_error_code::INVALID_TYPE_CAST);
+
+        let dec = if dec.scale() > 19 {
+            dec.normalize()
+        } else {
+            dec
+        };
+        let val_scaled = i64::try_from(dec.mantissa()).map_err(|_| invalid())?;
+
+        Numeric::new(val_scaled, dec.scale() as usize).ok_or_else(invalid)
+    }
+}
+
 impl Display for Numeric {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::result::Result<(), FmtError> {
-        write!(f, "{}.{}", self.int_part(), self.fract_part())
+        let sign = if self.val_scaled < 0 { "-" } else { "" };
+        let int_part = (self.val_scaled as i128 / self.scale()).unsigned_abs();
+
+        if self.prec == 0 {
+            write!(f, "{}{}", sign, int_part)
+        } else {
+            write!(
+                f,
+                "{}{}.{:0width$}",
+                sign,
+                int_part,
+                self.fract_part(),
+                width = self.prec
+            )
+        }
+    }
+}
+
+impl str::FromStr for Numeric {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let invalid = || Error::new_sql(mcosql_error_code::INVALID_TYPE_CAST);
+
+        let (neg, digits) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+
+        let (int_part, fract_part) = match digits.find('.') {
+            Some(pos) if pos + 1 < digits.len() => (&digits[..pos], &digits[pos + 1..]),
+            Some(_) => return Err(invalid()),
+            None => (digits, ""),
+        };
+
+        if int_part.is_empty()
+            || !int_part
+                .bytes()
+                .chain(fract_part.bytes())
+                .all(|b| b.is_ascii_digit())
+        {
+            return Err(invalid());
+        }
+
+        let mut val_scaled = 0i64;
+        for b in int_part.bytes().chain(fract_part.bytes()) {
+            let digit = (b - b'0') as i64;
+            val_scaled = val_scaled
+                .checked_mul(10)
+                .and_then(|v| {
+                    if neg {
+                        v.checked_sub(digit)
+                    } else {
+                        v.checked_add(digit)
+                    }
+                })
+                .ok_or_else(invalid)?;
+        }
+
+        Numeric::new(val_scaled, fract_part.len()).ok_or_else(invalid)
     }
 }
 
@@ -1453,6 +4342,58 @@ impl ToValue for Numeric {
     }
 }
 
+/// A date/time value expressed in raw ticks.
+///
+/// Values of this type are bound to the SQL statements as date/time values
+/// containing the exact number of ticks, without any scaling. In contrast,
+/// `SystemTime` values are converted to ticks according to the
+/// [date/time precision] of the runtime.
+///
+/// This is useful when the tick value is already known, for example when it
+/// was obtained using [`Value::to_date_time()`].
+///
+/// # Examples
+///
+/// ```
+/// # use extremedb::sql::engine::Engine;
+/// # use extremedb::sql::value::Ticks;
+/// # use extremedb::{connection, database, device, runtime, sql};
+/// # use extremedb::device::util;
+/// # fn main() -> extremedb::Result<()> {
+/// #     let runtime = runtime::Runtime::start(vec![]);
+/// #     let mut db_params = database::Params::new();
+/// #     db_params
+/// #         .ddl_dict_size(32768)
+/// #         .max_classes(100)
+/// #         .max_indexes(1000);
+/// #     let mut devs = util::DeviceContainer::new();
+/// #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+/// #     let conn = connection::Connection::new(&db)?;
+/// #     let engine = sql::engine::LocalEngine::new(&conn)?;
+///     engine.execute_statement("CREATE TABLE TestTable(t timestamp);", &[])?;
+///     engine.execute_statement("INSERT INTO TestTable(t) VALUES(?);", &[&Ticks(123456789)])?;
+///
+///     let ds = engine.execute_query("SELECT t FROM TestTable;", &[])?.unwrap();
+///     let mut cur = ds.cursor()?;
+///     assert!(cur.advance()?);
+///
+///     let rec = cur.current_record().unwrap();
+///     assert_eq!(rec.get_at(0)?.to_date_time()?, 123456789);
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [date/time precision]: ../../runtime/options/struct.DateTimePrecision.html
+/// [`Value::to_date_time()`]: ./struct.Value.html#method.to_date_time
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Ticks(pub u64);
+
+impl ToValue for Ticks {
+    fn to_value<'a>(&self, alloc: AllocatorRef<'a>) -> Result<Value<'a>> {
+        Value::new_date_time_ticks(self.0, alloc)
+    }
+}
+
 /// An SQL blob.
 ///
 /// An *e*X*treme*DB SQL blob is a large binary object that can contain
@@ -1486,6 +4427,59 @@ impl<'a> Blob<'a> {
             .and(Ok(avail as usize))
     }
 
+    /// Returns the total size of the blob data, in bytes.
+    ///
+    /// Unlike [`available()`], this method reports the size of all the
+    /// segments of the blob. It does not affect the read pointer, and can
+    /// be used to allocate a buffer before reading the data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use extremedb::sql::engine::Engine;
+    /// # use extremedb::{connection, database, runtime, sql};
+    /// # use extremedb::device::util;
+    /// # fn main() -> extremedb::Result<()> {
+    /// #     let runtime = runtime::Runtime::start(vec![]);
+    /// #     let mut db_params = database::Params::new();
+    /// #     db_params
+    /// #         .ddl_dict_size(32768)
+    /// #         .max_classes(100)
+    /// #         .max_indexes(1000);
+    /// #     let mut devs = util::DeviceContainer::new();
+    /// #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+    /// #     let conn = connection::Connection::new(&db)?;
+    /// #     let engine = sql::engine::LocalEngine::new(&conn)?;
+    ///     engine.execute_statement("CREATE TABLE TestTable(b blob);", &[])?;
+    ///
+    ///     // Large enough to be split into multiple segments.
+    ///     let data = vec![0xa5u8; 100_000];
+    ///     let data_ref: &[u8] = &data;
+    ///     engine.execute_statement("INSERT INTO TestTable(b) VALUES(?);", &[&data_ref])?;
+    ///
+    ///     let ds = engine.execute_query("SELECT b FROM TestTable;", &[])?.unwrap();
+    ///     let mut cur = ds.cursor()?;
+    ///     assert!(cur.advance()?);
+    ///
+    ///     let rec = cur.current_record().unwrap();
+    ///     let val = rec.get_at(0)?;
+    ///     let blob = val.as_blob()?;
+    ///
+    ///     let size = blob.total_size()?;
+    ///     assert_eq!(size, data.len());
+    ///
+    ///     let mut buf = Vec::with_capacity(size);
+    ///     blob.get_into(&mut buf)?;
+    ///     assert_eq!(buf, data);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`available()`]: #method.available
+    pub fn total_size(&self) -> Result<usize> {
+        self.val.size()
+    }
+
     /// Reads the blob data into the buffer.
     ///
     /// This method will fill the buffer up to its capacity. If the number
@@ -1513,6 +4507,54 @@ impl<'a> Blob<'a> {
         result_from_code(unsafe { exdb_sys::mcosql_rs_blob_reset(self.val.h, 0) })
     }
 
+    /// Returns a reader for the blob data.
+    ///
+    /// The reader implements `std::io::Read`, and shares the read pointer
+    /// with the blob.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use extremedb::sql::engine::Engine;
+    /// # use extremedb::{connection, database, runtime, sql};
+    /// # use extremedb::device::util;
+    /// # use std::io::Read;
+    /// # fn main() -> extremedb::Result<()> {
+    /// #     let runtime = runtime::Runtime::start(vec![]);
+    /// #     let mut db_params = database::Params::new();
+    /// #     db_params
+    /// #         .ddl_dict_size(32768)
+    /// #         .max_classes(100)
+    /// #         .max_indexes(1000);
+    /// #     let mut devs = util::DeviceContainer::new();
+    /// #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+    /// #     let conn = connection::Connection::new(&db)?;
+    /// #     let engine = sql::engine::LocalEngine::new(&conn)?;
+    ///     engine.execute_statement("CREATE TABLE TestTable(b blob);", &[])?;
+    ///
+    ///     // Large enough to be split into multiple segments.
+    ///     let data: Vec<u8> = (0..100_000).map(|i| (i % 251) as u8).collect();
+    ///     let data_ref: &[u8] = &data;
+    ///     engine.execute_statement("INSERT INTO TestTable(b) VALUES(?);", &[&data_ref])?;
+    ///
+    ///     let ds = engine.execute_query("SELECT b FROM TestTable;", &[])?.unwrap();
+    ///     let mut cur = ds.cursor()?;
+    ///     assert!(cur.advance()?);
+    ///
+    ///     let rec = cur.current_record().unwrap();
+    ///     let val = rec.get_at(0)?;
+    ///     let blob = val.as_blob()?;
+    ///
+    ///     let mut read = Vec::new();
+    ///     blob.reader().read_to_end(&mut read).unwrap();
+    ///     assert_eq!(read, data);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn reader(&self) -> BlobReader<'_> {
+        BlobReader { blob: self }
+    }
+
     unsafe fn get_raw(&self, p: *mut c_void, l: usize) -> Result<usize> {
         let mut total: exdb_sys::size_t = 0;
         let lsz = l as exdb_sys::size_t;
@@ -1538,6 +4580,25 @@ impl<'a> Blob<'a> {
     }
 }
 
+/// A reader for the blob data.
+///
+/// This type is returned by [`Blob::reader()`]. Reading returns zero bytes
+/// at the end of the blob; errors are reported as `std::io::Error`s of
+/// the `Other` kind, wrapping the original [`Error`].
+///
+/// [`Blob::reader()`]: ./struct.Blob.html#method.reader
+/// [`Error`]: ../../enum.Error.html
+pub struct BlobReader<'a> {
+    blob: &'a Blob<'a>,
+}
+
+impl<'a> io::Read for BlobReader<'a> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let p = buf.as_mut_ptr() as *mut c_void;
+        unsafe { self.blob.get_raw(p, buf.len()) }.map_err(io::Error::other)
+    }
+}
+
 /// A `Binary` value wrapper.
 ///
 /// The sole purpose of this type is passing `Binary` values to the SQL engine.
@@ -1550,8 +4611,97 @@ pub struct Binary<'a>(&'a [u8]);
 
 impl<'a> Binary<'a> {
     /// Creates a new `Binary` value wrapper.
-    pub fn new(bytes: &'a [u8]) -> Self {
-        Binary(bytes)
+    ///
+    /// Any borrowed byte container can be wrapped, including byte slices,
+    /// arrays, and vectors.
+    ///
+    /// # Examples
+    ///
+    /// Inserting a byte vector built at runtime:
+    ///
+    /// ```
+    /// # use extremedb::sql::engine::Engine;
+    /// # use extremedb::sql::value::Binary;
+    /// # use extremedb::{connection, database, runtime, sql};
+    /// # use extremedb::device::util;
+    /// # fn main() -> extremedb::Result<()> {
+    /// #     let runtime = runtime::Runtime::start(vec![]);
+    /// #     let mut db_params = database::Params::new();
+    /// #     db_params
+    /// #         .ddl_dict_size(32768)
+    /// #         .max_classes(100)
+    /// #         .max_indexes(1000);
+    /// #     let mut devs = util::DeviceContainer::new();
+    /// #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+    /// #     let conn = connection::Connection::new(&db)?;
+    /// #     let engine = sql::engine::LocalEngine::new(&conn)?;
+    ///     engine.execute_statement("CREATE TABLE TestTable(b varbinary);", &[])?;
+    ///
+    ///     let bytes: Vec<u8> = (0..=255).collect();
+    ///
+    ///     engine.execute_statement(
+    ///         "INSERT INTO TestTable(b) VALUES(?);",
+    ///         &[&Binary::new(&bytes)],
+    ///     )?;
+    ///
+    ///     let ds = engine.execute_query("SELECT b FROM TestTable;", &[])?.unwrap();
+    ///     let mut cur = ds.cursor()?;
+    ///     assert!(cur.advance()?);
+    ///
+    ///     let rec = cur.current_record().unwrap();
+    ///     assert_eq!(rec.get_at(0)?.as_bytes()?, &bytes[..]);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn new<T: AsRef<[u8]> + ?Sized>(bytes: &'a T) -> Self {
+        Binary(bytes.as_ref())
+    }
+
+    /// Returns the wrapped bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use extremedb::sql::value::Binary;
+    /// let b = Binary::new(b"Some binary data");
+    /// assert_eq!(b.as_bytes(), b"Some binary data");
+    /// assert_eq!(b.len(), 16);
+    /// assert!(!b.is_empty());
+    /// ```
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.0
+    }
+
+    /// Returns the number of wrapped bytes.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if the wrapped byte slice is empty.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// A reference to the contents of a `Binary` value.
+///
+/// This type is returned by [`Value::as_binary_ref()`]. It borrows the
+/// value, and dereferences to its bytes.
+///
+/// [`Value::as_binary_ref()`]: ./struct.Value.html#method.as_binary_ref
+pub struct BinaryRef<'a>(&'a [u8]);
+
+impl<'a> Deref for BinaryRef<'a> {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        self.0
+    }
+}
+
+impl<'a> AsRef<[u8]> for BinaryRef<'a> {
+    fn as_ref(&self) -> &[u8] {
+        self.0
     }
 }
 
@@ -1627,6 +4777,96 @@ impl ToValue for &str {
     }
 }
 
+/// Owned strings can be passed as statement parameters directly, without
+/// converting them to `&str` first.
+///
+/// # Examples
+///
+/// ```
+/// # use extremedb::{connection, database, runtime, sql};
+/// # use extremedb::device::util;
+/// # use extremedb::sql::engine::Engine;
+/// # fn main() -> extremedb::Result<()> {
+/// #     let runtime = runtime::Runtime::start(vec![]);
+/// #     let mut db_params = database::Params::new();
+/// #     db_params
+/// #         .ddl_dict_size(32768)
+/// #         .max_classes(100)
+/// #         .max_indexes(1000);
+/// #     let mut devs = util::DeviceContainer::new();
+/// #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+/// #     let conn = connection::Connection::new(&db)?;
+/// #     let engine = sql::engine::LocalEngine::new(&conn)?;
+///     engine.execute_statement("CREATE TABLE TestTable(i integer, s string);", &[])?;
+///
+///     let names: Vec<String> = (0..3).map(|i| format!("name-{}", i)).collect();
+///
+///     for (i, name) in names.iter().enumerate() {
+///         engine.execute_statement(
+///             "INSERT INTO TestTable(i, s) VALUES(?, ?);",
+///             &[&(i as i64), &name],
+///         )?;
+///     }
+///
+///     let ds = engine
+///         .execute_query("SELECT s FROM TestTable ORDER BY i;", &[])?
+///         .unwrap();
+///     let mut cur = ds.cursor()?;
+///
+///     for name in &names {
+///         assert!(cur.advance()?);
+///         let rec = cur.current_record().unwrap();
+///         assert_eq!(&rec.get_at(0)?.to_string()?, name);
+///     }
+/// #     Ok(())
+/// # }
+/// ```
+impl ToValue for String {
+    fn to_value<'a>(&self, alloc: AllocatorRef<'a>) -> Result<Value<'a>> {
+        Value::new_string(self, alloc)
+    }
+}
+
+/// Characters are passed as single-character strings.
+///
+/// # Examples
+///
+/// ```
+/// # use extremedb::{connection, database, runtime, sql};
+/// # use extremedb::device::util;
+/// # use extremedb::sql::engine::Engine;
+/// # fn main() -> extremedb::Result<()> {
+/// #     let runtime = runtime::Runtime::start(vec![]);
+/// #     let mut db_params = database::Params::new();
+/// #     db_params
+/// #         .ddl_dict_size(32768)
+/// #         .max_classes(100)
+/// #         .max_indexes(1000);
+/// #     let mut devs = util::DeviceContainer::new();
+/// #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+/// #     let conn = connection::Connection::new(&db)?;
+/// #     let engine = sql::engine::LocalEngine::new(&conn)?;
+///     engine.execute_statement("CREATE TABLE TestTable(c string);", &[])?;
+///     engine.execute_statement("INSERT INTO TestTable(c) VALUES(?);", &[&'\u{e9}'])?;
+///
+///     let ds = engine.execute_query("SELECT c FROM TestTable;", &[])?.unwrap();
+///     let mut cur = ds.cursor()?;
+///     assert!(cur.advance()?);
+///
+///     let rec = cur.current_record().unwrap();
+///     let s = rec.get_at(0)?.to_string()?;
+///     assert_eq!(s.chars().count(), 1);
+///     assert_eq!(s, "\u{e9}");
+/// #     Ok(())
+/// # }
+/// ```
+impl ToValue for char {
+    fn to_value<'a>(&self, alloc: AllocatorRef<'a>) -> Result<Value<'a>> {
+        let mut buf = [0u8; 4];
+        Value::new_string(self.encode_utf8(&mut buf), alloc)
+    }
+}
+
 impl ToValue for Binary<'_> {
     fn to_value<'a>(&self, alloc: AllocatorRef<'a>) -> Result<Value<'a>> {
         Value::new_binary(self.0, alloc)
@@ -1640,6 +4880,32 @@ impl<T: ArrayElem> ToValue for &[T] {
     }
 }
 
+// Fixed-size Rust arrays and vectors are converted to SQL arrays. The
+// impls are not provided for `u8` elements, so that byte arrays and byte
+// string literals are not silently bound as arrays instead of binary
+// values.
+macro_rules! impl_array_to_value {
+    ($($ty:ty),*) => {
+        $(
+            impl<const N: usize> ToValue for [$ty; N] {
+                fn to_value<'a>(&self, alloc: AllocatorRef<'a>) -> Result<Value<'a>> {
+                    self.as_slice().to_value(alloc)
+                }
+            }
+
+            impl ToValue for Vec<$ty> {
+                fn to_value<'a>(&self, alloc: AllocatorRef<'a>) -> Result<Value<'a>> {
+                    self.as_slice().to_value(alloc)
+                }
+            }
+        )*
+    };
+}
+
+impl_array_to_value!(
+    bool, u16, u32, u64, i8, i16, i32, i64, f32, f64, &str, String, &String, char, SystemTime
+);
+
 impl<T: ToValue> ToValue for Option<T> {
     fn to_value<'a>(&self, alloc: AllocatorRef<'a>) -> Result<Value<'a>> {
         match self {
@@ -1655,6 +4921,78 @@ impl ToValue for SystemTime {
     }
 }
 
+/// UTC timestamps are converted like `std::time::SystemTime` values; the
+/// timestamps preceding the epoch are rejected with an `INVALID_TYPE_CAST`
+/// error.
+#[cfg(feature = "chrono")]
+impl ToValue for DateTime<Utc> {
+    fn to_value<'a>(&self, alloc: AllocatorRef<'a>) -> Result<Value<'a>> {
+        Value::new_date_time(&SystemTime::from(*self), alloc)
+    }
+}
+
+/// Date-time values are converted like `std::time::SystemTime` values,
+/// regardless of their offset; the values preceding the epoch are rejected
+/// with an `INVALID_TYPE_CAST` error.
+#[cfg(feature = "time")]
+impl ToValue for OffsetDateTime {
+    fn to_value<'a>(&self, alloc: AllocatorRef<'a>) -> Result<Value<'a>> {
+        Value::new_date_time(&SystemTime::from(*self), alloc)
+    }
+}
+
+/// Durations are converted to the number of system ticks, according to the
+/// runtime's `datetime` precision (see [`DateTimePrecision`]).
+///
+/// The fractions of a tick are truncated. This allows for storing
+/// elapsed times or offsets in `timestamp` columns.
+///
+/// # Examples
+///
+/// ```
+/// # use extremedb::{connection, database, runtime, sql};
+/// # use extremedb::device::util;
+/// # use extremedb::sql::engine::Engine;
+/// # use std::time::Duration;
+/// # fn main() -> extremedb::Result<()> {
+/// #     let runtime = runtime::Runtime::start(vec![]);
+/// #     let mut db_params = database::Params::new();
+/// #     db_params
+/// #         .ddl_dict_size(32768)
+/// #         .max_classes(100)
+/// #         .max_indexes(1000);
+/// #     let mut devs = util::DeviceContainer::new();
+/// #     let db = database::Database::open(&runtime, "test_db", None, devs.devices(), db_params)?;
+/// #     let conn = connection::Connection::new(&db)?;
+/// #     let engine = sql::engine::LocalEngine::new(&conn)?;
+///     engine.execute_statement("CREATE TABLE TestTable(elapsed timestamp);", &[])?;
+///
+///     // The default precision is one second.
+///     let elapsed = Duration::from_millis(90_500);
+///     engine.execute_statement("INSERT INTO TestTable(elapsed) VALUES(?);", &[&elapsed])?;
+///
+///     let ds = engine.execute_query("SELECT elapsed FROM TestTable;", &[])?.unwrap();
+///     let mut cur = ds.cursor()?;
+///     assert!(cur.advance()?);
+///     let rec = cur.current_record().unwrap();
+///     assert_eq!(rec.get_at(0)?.to_date_time()?, 90);
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [`DateTimePrecision`]: ../../runtime/options/struct.DateTimePrecision.html
+impl ToValue for Duration {
+    fn to_value<'a>(&self, alloc: AllocatorRef<'a>) -> Result<Value<'a>> {
+        Value::new_duration(self, alloc)
+    }
+}
+
+impl<T: ToValue> ToValue for &T {
+    fn to_value<'a>(&self, alloc: AllocatorRef<'a>) -> Result<Value<'a>> {
+        (*self).to_value(alloc)
+    }
+}
+
 /// A trait for retrieving the SQL type of the implementing Rust type.
 pub trait StaticTypeInfo {
     fn static_type() -> Type;
@@ -1670,6 +5008,7 @@ macro_rules! impl_static_type_info {
     };
 }
 
+impl_static_type_info!(bool, Type::Bool);
 impl_static_type_info!(u8, Type::UInt1);
 impl_static_type_info!(u16, Type::UInt2);
 impl_static_type_info!(u32, Type::UInt4);
@@ -1681,6 +5020,9 @@ impl_static_type_info!(i64, Type::Int8);
 impl_static_type_info!(f32, Type::Real4);
 impl_static_type_info!(f64, Type::Real8);
 impl_static_type_info!(&str, Type::String);
+impl_static_type_info!(String, Type::String);
+impl_static_type_info!(&String, Type::String);
+impl_static_type_info!(char, Type::String);
 impl_static_type_info!(SystemTime, Type::Time);
 
 /// A marker trait for types that can be an element of an SQL array.
@@ -0,0 +1,154 @@
+// lib.rs
+//
+// This file is a part of the eXtremeDB source code
+// Copyright (c) 2020 McObject LLC
+// All Rights Reserved
+
+//! Derive macros for the `extremedb` crate.
+//!
+//! This crate is not intended to be used directly. Enable the `derive`
+//! feature of the `extremedb` crate to use the macros it provides.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::ext::IdentExt;
+use syn::punctuated::Punctuated;
+use syn::token::Comma;
+use syn::{parse_macro_input, Data, DeriveInput, Field, Fields, LitStr};
+
+/// Derives the `FromRow` trait for a structure with named fields.
+///
+/// Each field is read from the record column with the same name, and
+/// converted using the field type's `FromValue` implementation. The column
+/// name can be overridden using the `#[extremedb(rename = "name")]`
+/// attribute. Missing columns and failed conversions are reported as
+/// errors by the generated `from_row()` method; the record columns which
+/// do not correspond to any field are ignored.
+#[proc_macro_derive(FromRow, attributes(extremedb))]
+pub fn derive_from_row(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand_from_row(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derives the `ToRow` trait for a structure with named fields.
+///
+/// Each field is stored in the column with the same name, in the order of
+/// declaration; the field types must implement `ToValue`. The column name
+/// can be overridden using the `#[extremedb(rename = "name")]` attribute.
+#[proc_macro_derive(ToRow, attributes(extremedb))]
+pub fn derive_to_row(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand_to_row(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand_from_row(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let fields = named_fields(input, "FromRow")?;
+
+    let mut inits = Vec::with_capacity(fields.len());
+
+    for field in fields {
+        let ident = field.ident.as_ref().unwrap();
+        let ty = &field.ty;
+        let column = column_name(field)?;
+
+        inits.push(quote! {
+            #ident: <#ty as ::extremedb::sql::value::FromValue>::from_value(
+                &*rec.get_by_name(#column)?,
+            )?
+        });
+    }
+
+    Ok(quote! {
+        impl #impl_generics ::extremedb::sql::data_source::FromRow for #name #ty_generics
+            #where_clause
+        {
+            fn from_row(
+                rec: &::extremedb::sql::data_source::Record,
+            ) -> ::extremedb::Result<Self> {
+                ::std::result::Result::Ok(#name {
+                    #(#inits,)*
+                })
+            }
+        }
+    })
+}
+
+fn expand_to_row(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let fields = named_fields(input, "ToRow")?;
+
+    let idents = fields.iter().map(|field| field.ident.as_ref().unwrap());
+    let columns = fields
+        .iter()
+        .map(column_name)
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    Ok(quote! {
+        impl #impl_generics ::extremedb::sql::engine::ToRow for #name #ty_generics
+            #where_clause
+        {
+            fn columns() -> &'static [&'static str] {
+                &[#(#columns),*]
+            }
+
+            fn values(&self) -> ::std::vec::Vec<&dyn ::extremedb::sql::value::ToValue> {
+                ::std::vec![#(&self.#idents as &dyn ::extremedb::sql::value::ToValue),*]
+            }
+        }
+    })
+}
+
+fn named_fields<'a>(
+    input: &'a DeriveInput,
+    trait_name: &str,
+) -> syn::Result<&'a Punctuated<Field, Comma>> {
+    match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => Ok(&fields.named),
+            _ => Err(syn::Error::new_spanned(
+                &input.ident,
+                format!(
+                    "{} can only be derived for structures with named fields",
+                    trait_name
+                ),
+            )),
+        },
+        _ => Err(syn::Error::new_spanned(
+            &input.ident,
+            format!("{} can only be derived for structures", trait_name),
+        )),
+    }
+}
+
+// Returns the name of the column the field is mapped to.
+fn column_name(field: &Field) -> syn::Result<LitStr> {
+    let ident = field.ident.as_ref().unwrap();
+    let mut ret = LitStr::new(&ident.unraw().to_string(), ident.span());
+
+    for attr in &field.attrs {
+        if !attr.path().is_ident("extremedb") {
+            continue;
+        }
+
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename") {
+                ret = meta.value()?.parse()?;
+                Ok(())
+            } else {
+                Err(meta.error("unsupported extremedb attribute"))
+            }
+        })?;
+    }
+
+    Ok(ret)
+}
@@ -1062,5 +1062,9 @@ extern "C" {
 
     pub fn mco_db_disconnect(db: mco_db_h) -> MCO_RET;
 
+    pub fn mco_db_free_pages(db: mco_db_h, freepages: *mut mco_counter32_t) -> MCO_RET;
+
+    pub fn mco_db_total_pages(db: mco_db_h, totalpages: *mut mco_counter32_t) -> MCO_RET;
+
     pub fn mco_strerror(rc: MCO_RET) -> *const ::std::os::raw::c_char;
 }
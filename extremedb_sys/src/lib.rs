@@ -84,6 +84,13 @@
 mod core;
 pub use crate::core::*;
 
+/// The value of the `MCORS_CFG_TMGR` environment variable at build time.
+///
+/// This identifies the transaction manager library linked into the
+/// application. It is `None` if the variable was not set (for example, when
+/// building the documentation).
+pub const MCORS_CFG_TMGR: Option<&str> = option_env!("MCORS_CFG_TMGR");
+
 #[cfg(feature = "sql")]
 mod sql;
 #[cfg(feature = "sql")]